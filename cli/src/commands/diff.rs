@@ -85,6 +85,31 @@ pub async fn run(opts: Opts, output: &mut impl std::io::Write) -> color_eyre::Re
                         }
                     }
 
+                    if !pallet_diff.events.is_empty() {
+                        writeln!(output, "        Events:")?;
+                        for diff in pallet_diff.events {
+                            match diff {
+                                Diff::Added(new) => writeln!(
+                                    output,
+                                    "{}",
+                                    format!("            + {}", &new.name).green()
+                                )?,
+                                Diff::Removed(old) => writeln!(
+                                    output,
+                                    "{}",
+                                    format!("            - {}", &old.name).red()
+                                )?,
+                                Diff::Changed { from, to: _ } => {
+                                    writeln!(
+                                        output,
+                                        "{}",
+                                        format!("            ~ {}", &from.name).yellow()
+                                    )?;
+                                }
+                            }
+                        }
+                    }
+
                     if !pallet_diff.constants.is_empty() {
                         writeln!(output, "        Constants:")?;
                         for diff in pallet_diff.constants {
@@ -191,6 +216,7 @@ impl<'a> MetadataDiff<'a> {
 #[derive(Default)]
 struct PalletDiff<'a> {
     calls: Vec<Diff<&'a Variant<PortableForm>>>,
+    events: Vec<Diff<&'a Variant<PortableForm>>>,
     constants: Vec<Diff<&'a ConstantMetadata>>,
     storage_entries: Vec<Diff<&'a StorageEntryMetadata>>,
 }
@@ -201,10 +227,12 @@ impl<'a> PalletDiff<'a> {
         pallet_metadata_2: &'a PalletMetadata<'a>,
     ) -> PalletDiff<'a> {
         let calls = calls_differences(pallet_metadata_1, pallet_metadata_2);
+        let events = events_differences(pallet_metadata_1, pallet_metadata_2);
         let constants = constants_differences(pallet_metadata_1, pallet_metadata_2);
         let storage_entries = storage_differences(pallet_metadata_1, pallet_metadata_2);
         PalletDiff {
             calls,
+            events,
             constants,
             storage_entries,
         }
@@ -341,6 +369,27 @@ fn calls_differences<'a>(
     );
 }
 
+fn events_differences<'a>(
+    pallet_metadata_1: &'a PalletMetadata<'a>,
+    pallet_metadata_2: &'a PalletMetadata<'a>,
+) -> Vec<Diff<&'a Variant<PortableForm>>> {
+    diff(
+        pallet_metadata_1.event_variants().unwrap_or_default(),
+        pallet_metadata_2.event_variants().unwrap_or_default(),
+        |e| {
+            pallet_metadata_1
+                .event_hash(&e.name)
+                .expect("event is in metadata; qed")
+        },
+        |e| {
+            pallet_metadata_2
+                .event_hash(&e.name)
+                .expect("event is in metadata; qed")
+        },
+        |e| &e.name,
+    )
+}
+
 fn constants_differences<'a>(
     pallet_metadata_1: &'a PalletMetadata<'a>,
     pallet_metadata_2: &'a PalletMetadata<'a>,
@@ -3,10 +3,11 @@
 // see LICENSE for license details.
 
 use crate::config::signed_extensions::{
-    ChargeAssetTxPayment, ChargeTransactionPayment, CheckNonce,
+    ChargeAssetTxPayment, ChargeTransactionPayment, CheckMortality, CheckNonce,
 };
 use crate::config::SignedExtension;
 use crate::dynamic::Value;
+use crate::utils::Era;
 use crate::{config::Config, error::Error, Metadata};
 use frame_decode::extrinsics::ExtrinsicExtensions;
 use scale_decode::DecodeAsType;
@@ -86,6 +87,13 @@ impl<'a, T: Config> ExtrinsicSignedExtensions<'a, T> {
     pub fn nonce(&self) -> Option<u64> {
         self.find::<CheckNonce>().ok()?
     }
+
+    /// The era of the extrinsic, extracted from the CheckMortality signed extension.
+    ///
+    /// Returns `None` if `era` was not found or decoding failed.
+    pub fn era(&self) -> Option<Era> {
+        self.find::<CheckMortality<T>>().ok()?
+    }
 }
 
 /// A single signed extension
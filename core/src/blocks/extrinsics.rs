@@ -11,6 +11,7 @@ use crate::{
 };
 use alloc::sync::Arc;
 use alloc::vec::Vec;
+use codec::Decode;
 use core::ops::Deref;
 use frame_decode::extrinsics::Extrinsic;
 use scale_decode::DecodeAsType;
@@ -219,6 +220,21 @@ where
             .map(|s| &self.bytes()[s.address_range()])
     }
 
+    /// Decode the address that signed this extrinsic into the configured address type.
+    /// For Polkadot-like chains this is a [`crate::utils::MultiAddress`], whose `Id`
+    /// variant wraps the signer's account ID.
+    ///
+    /// # Note
+    ///
+    /// Returns `None` if the extrinsic is not signed.
+    pub fn address(&self) -> Option<Result<T::Address, Error>>
+    where
+        T::Address: Decode,
+    {
+        self.address_bytes()
+            .map(|mut bytes| T::Address::decode(&mut bytes).map_err(Error::Codec))
+    }
+
     /// Returns Some(signature_bytes) if the extrinsic was signed otherwise None is returned.
     pub fn signature_bytes(&self) -> Option<&[u8]> {
         self.decoded_info()
@@ -226,6 +242,19 @@ where
             .map(|s| &self.bytes()[s.signature_range()])
     }
 
+    /// Decode the signature of this extrinsic into the configured signature type.
+    ///
+    /// # Note
+    ///
+    /// Returns `None` if the extrinsic is not signed.
+    pub fn signature(&self) -> Option<Result<T::Signature, Error>>
+    where
+        T::Signature: Decode,
+    {
+        self.signature_bytes()
+            .map(|mut bytes| T::Signature::decode(&mut bytes).map_err(Error::Codec))
+    }
+
     /// Returns the signed extension `extra` bytes of the extrinsic.
     /// Each signed extension has an `extra` type (May be zero-sized).
     /// These bytes are the scale encoded `extra` fields of each signed extension in order of the signed extensions.
@@ -354,7 +383,10 @@ mod tests {
     use codec::{Decode, Encode};
     use frame_metadata::v15::{CustomMetadata, OuterEnums};
     use frame_metadata::{
-        v15::{ExtrinsicMetadata, PalletCallMetadata, PalletMetadata, RuntimeMetadataV15},
+        v15::{
+            ExtrinsicMetadata, PalletCallMetadata, PalletMetadata, RuntimeMetadataV15,
+            SignedExtensionMetadata,
+        },
         RuntimeMetadataPrefixed,
     };
     use scale_info::{meta_type, TypeInfo};
@@ -436,6 +468,15 @@ mod tests {
 
     /// Build fake metadata consisting the types needed to represent an extrinsic.
     fn metadata() -> Metadata {
+        metadata_with_signature_types(meta_type::<()>(), meta_type::<()>(), vec![])
+    }
+
+    /// Build fake metadata like [`metadata()`], but with the given address and signature types.
+    fn metadata_with_signature_types(
+        address_ty: scale_info::MetaType,
+        signature_ty: scale_info::MetaType,
+        signed_extensions: Vec<SignedExtensionMetadata>,
+    ) -> Metadata {
         let pallets = vec![PalletMetadata {
             name: "Test",
             storage: None,
@@ -451,10 +492,10 @@ mod tests {
 
         let extrinsic = ExtrinsicMetadata {
             version: 4,
-            signed_extensions: vec![],
-            address_ty: meta_type::<()>(),
+            signed_extensions,
+            address_ty,
             call_ty: meta_type::<RuntimeCall>(),
-            signature_ty: meta_type::<()>(),
+            signature_ty,
             extra_ty: meta_type::<()>(),
         };
 
@@ -637,4 +678,87 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn decode_signer_address_and_signature() {
+        use crate::config::PolkadotConfig;
+        use crate::utils::{AccountId32, Era, MultiAddress, MultiSignature};
+        use polkadot_sdk::sp_runtime::generic::Era as SpEra;
+
+        // Runtimes describe the era with an `Immortal` variant and a variant for each
+        // possible first byte of a mortal era, as the `sp_runtime` type does.
+        let metadata = metadata_with_signature_types(
+            meta_type::<MultiAddress<AccountId32, ()>>(),
+            meta_type::<MultiSignature>(),
+            vec![SignedExtensionMetadata {
+                identifier: "CheckMortality",
+                ty: meta_type::<SpEra>(),
+                additional_signed: meta_type::<[u8; 32]>(),
+            }],
+        );
+
+        let account = AccountId32([7; 32]);
+        let address: MultiAddress<AccountId32, ()> = MultiAddress::Id(account.clone());
+        let signature = MultiSignature::Sr25519([9; 64]);
+        let call = RuntimeCall::Test(Pallet::TestCall {
+            value: 10,
+            signed: true,
+            name: "SomeValue".into(),
+        });
+
+        // A signed v4 extrinsic: version byte, address, signature, extra (era), call.
+        let signed = |era: Era| {
+            let mut signed = vec![0b1000_0100];
+            address.encode_to(&mut signed);
+            signature.encode_to(&mut signed);
+            era.encode_to(&mut signed);
+            call.encode_to(&mut signed);
+            signed
+        };
+        let mortal_era = Era::mortal(64, 1_000);
+
+        // An unsigned v4 extrinsic: version byte, call.
+        let mut unsigned = vec![0b0000_0100];
+        call.encode_to(&mut unsigned);
+
+        let extrinsics = Extrinsics::<PolkadotConfig>::decode_from(
+            vec![
+                signed(mortal_era).encode(),
+                signed(Era::Immortal).encode(),
+                unsigned.encode(),
+            ],
+            metadata,
+        )
+        .expect("Valid extrinsics");
+        let mut iter = extrinsics.iter();
+
+        let signed = iter.next().unwrap();
+        assert!(signed.is_signed());
+        let decoded_address = signed
+            .address()
+            .expect("extrinsic is signed")
+            .expect("address decodes");
+        assert_eq!(decoded_address, MultiAddress::Id(account));
+        let decoded_signature = signed
+            .signature()
+            .expect("extrinsic is signed")
+            .expect("signature decodes");
+        assert_eq!(decoded_signature, signature);
+        assert_eq!(signed.variant_name().unwrap(), "TestCall");
+        let extensions = signed.signed_extensions().expect("extrinsic is signed");
+        assert_eq!(extensions.era(), Some(mortal_era));
+
+        let signed_immortal = iter.next().unwrap();
+        let extensions = signed_immortal
+            .signed_extensions()
+            .expect("extrinsic is signed");
+        assert_eq!(extensions.era(), Some(Era::Immortal));
+        assert_eq!(signed_immortal.variant_name().unwrap(), "TestCall");
+
+        let unsigned = iter.next().unwrap();
+        assert!(!unsigned.is_signed());
+        assert!(unsigned.address().is_none());
+        assert!(unsigned.signature().is_none());
+        assert!(unsigned.signed_extensions().is_none());
+    }
 }
@@ -0,0 +1,93 @@
+// Copyright 2019-2024 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+//! Kusama specific configuration
+
+use super::{Config, DefaultExtrinsicParams, DefaultExtrinsicParamsBuilder};
+
+use crate::config::SubstrateConfig;
+pub use crate::utils::{AccountId32, MultiAddress, MultiSignature};
+pub use primitive_types::{H256, U256};
+
+/// Default set of commonly used types by Kusama nodes.
+///
+/// Kusama shares its core types with Polkadot. Differences such as the SS58 address
+/// prefix (2 on Kusama, rather than 0 on Polkadot) don't affect these types.
+// Note: The trait implementations exist just to make life easier,
+// but shouldn't strictly be necessary since users can't instantiate this type.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum KusamaConfig {}
+
+impl Config for KusamaConfig {
+    type Hash = <SubstrateConfig as Config>::Hash;
+    type AccountId = <SubstrateConfig as Config>::AccountId;
+    type Address = MultiAddress<Self::AccountId, ()>;
+    type Signature = <SubstrateConfig as Config>::Signature;
+    type Hasher = <SubstrateConfig as Config>::Hasher;
+    type Header = <SubstrateConfig as Config>::Header;
+    type ExtrinsicParams = KusamaExtrinsicParams<Self>;
+    type AssetId = u32;
+}
+
+/// A struct representing the signed extra and additional parameters required
+/// to construct a transaction for a kusama node.
+pub type KusamaExtrinsicParams<T> = DefaultExtrinsicParams<T>;
+
+/// A builder which leads to [`KusamaExtrinsicParams`] being constructed.
+/// This is what you provide to methods like `sign_and_submit()`.
+pub type KusamaExtrinsicParamsBuilder<T> = DefaultExtrinsicParamsBuilder<T>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::substrate::DigestItem;
+    use crate::config::Header;
+    use codec::{Decode, Encode};
+
+    // Kusama headers come back with hex encoded block numbers and BABE digest items; make
+    // sure that our header type copes with these, and hashes to the published block hash.
+    #[test]
+    fn can_decode_kusama_header() {
+        // The header of Kusama block #1, as returned by `chain_getHeader`.
+        let header_json = r#"
+            {
+                "parentHash": "0xb0a8d493285c2df73290dfb7e61f870f17b41801197a149ca93654499ea3dafe",
+                "number": "0x1",
+                "stateRoot": "0xfabb0c6e92d29e8bb2167f3c6fb0ddeb956a4278a3cf853661af74a076fc9cb7",
+                "extrinsicsRoot": "0xa35fb7f7616f5c979d48222b3d2fa7cb2331ef73954726714d91ca945cc34fd8",
+                "digest": {
+                    "logs": [
+                        "0x0642414245340201000000ef55a50f00000000",
+                        "0x044241424549040118ca239392960473fe1bc65f94ee27d890a49c1b200c006ff5dcc525330ecc16770100000000000000b46f01874ce7abbb5220e8fd89bede0adad14c73039d91e28e881823433e723f0100000000000000d684d9176d6eb69887540c9a89fa6097adea82fc4b0ff26d1062b488f352e179010000000000000068195a71bdde49117a616424bdc60a1733e96acb1da5aeab5d268cf2a572e94101000000000000001a0575ef4ae24bdfd31f4cb5bd61239ae67c12d4e64ae51ac756044aa6ad8200010000000000000018168f2aad0081a25728961ee00627cfe35e39833c805016632bf7c14da5800901000000000000000000000000000000000000000000000000000000000000000000000000000000",
+                        "0x054241424501014625284883e564bc1e4063f5ea2b49846cdddaa3761d04f543b698c1c3ee935c40d25b869247c36c6b8a8cbbd7bb2768f560ab7c276df3c62df357a7e3b1ec8d"
+                    ]
+                }
+            }
+        "#;
+
+        let header: <KusamaConfig as Config>::Header =
+            serde_json::from_str(header_json).expect("valid block header");
+
+        assert_eq!(header.number(), 1);
+        assert!(matches!(
+            &header.digest.logs[..],
+            [
+                DigestItem::PreRuntime(pre_runtime, _),
+                DigestItem::Consensus(consensus, _),
+                DigestItem::Seal(seal, _),
+            ] if [pre_runtime, consensus, seal] == [b"BABE"; 3]
+        ));
+
+        // The header SCALE encodes and decodes losslessly, and hashes to the published
+        // hash of Kusama block #1:
+        let encoded = header.encode();
+        let decoded = <KusamaConfig as Config>::Header::decode(&mut &*encoded).unwrap();
+        assert_eq!(decoded, header);
+        let expected_hash: H256 =
+            "0xcd9b8e2fc2f57c4570a86319b005832080e0c478ab41ae5d44e23705872f5ad3"
+                .parse()
+                .unwrap();
+        assert_eq!(header.hash(), expected_hash);
+    }
+}
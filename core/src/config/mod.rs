@@ -12,6 +12,7 @@ mod default_extrinsic_params;
 mod extrinsic_params;
 mod refine_params;
 
+pub mod kusama;
 pub mod polkadot;
 pub mod signed_extensions;
 pub mod substrate;
@@ -25,6 +26,7 @@ use serde::{de::DeserializeOwned, Serialize};
 
 pub use default_extrinsic_params::{DefaultExtrinsicParams, DefaultExtrinsicParamsBuilder};
 pub use extrinsic_params::{ExtrinsicParams, ExtrinsicParamsEncoder};
+pub use kusama::{KusamaConfig, KusamaExtrinsicParams, KusamaExtrinsicParamsBuilder};
 pub use polkadot::{PolkadotConfig, PolkadotExtrinsicParams, PolkadotExtrinsicParamsBuilder};
 pub use refine_params::{RefineParams, RefineParamsData};
 pub use signed_extensions::SignedExtension;
@@ -6,9 +6,13 @@
 //! transactions, storage and constant lookups.
 
 use crate::metadata::{DecodeWithMetadata, Metadata};
+use alloc::format;
+use alloc::string::ToString;
 use alloc::vec::Vec;
 use scale_decode::DecodeAsType;
+use scale_info::{TypeDef, TypeDefPrimitive};
 pub use scale_value::{At, Value};
+use scale_value::{Composite, Primitive, ValueDef};
 
 /// A [`scale_value::Value`] type endowed with contextual information
 /// regarding what type was used to decode each part of it. This implements
@@ -81,3 +85,126 @@ impl DecodedValueThunk {
         )
     }
 }
+
+/// The largest integer that a JavaScript number can represent exactly. Integers
+/// larger than this are converted to strings by [`value_to_json`].
+const MAX_SAFE_INTEGER: u128 = (1 << 53) - 1;
+
+/// Convert a [`DecodedValue`] into a [`serde_json::Value`], in a form which is safe to
+/// hand to JSON consumers that use JavaScript numbers. The metadata is used to look up
+/// the types that each part of the value was decoded from.
+///
+/// - Integers which a JavaScript number cannot represent exactly are converted into
+///   decimal strings.
+/// - Sequences and arrays of `u8` are converted into `0x` prefixed hex strings.
+/// - Named composites are converted into objects and unnamed ones into arrays.
+/// - Variants are converted into objects like `{ "name": "Some", "values": [1] }`.
+pub fn value_to_json(value: &DecodedValue, metadata: &Metadata) -> serde_json::Value {
+    match &value.value {
+        ValueDef::Composite(composite) if is_bytes_type(value.context, metadata) => {
+            let bytes: Vec<u8> = composite
+                .values()
+                .filter_map(|value| value.as_u128())
+                .map(|n| n as u8)
+                .collect();
+            format!("0x{}", hex::encode(bytes)).into()
+        }
+        ValueDef::Composite(composite) => composite_to_json(composite, metadata),
+        ValueDef::Variant(variant) => {
+            let mut object = serde_json::Map::new();
+            object.insert("name".into(), variant.name.clone().into());
+            object.insert(
+                "values".into(),
+                composite_to_json(&variant.values, metadata),
+            );
+            serde_json::Value::Object(object)
+        }
+        ValueDef::BitSequence(bits) => bits.iter().map(serde_json::Value::Bool).collect(),
+        ValueDef::Primitive(primitive) => primitive_to_json(primitive),
+    }
+}
+
+pub(crate) fn composite_to_json(
+    composite: &Composite<u32>,
+    metadata: &Metadata,
+) -> serde_json::Value {
+    match composite {
+        Composite::Named(fields) => fields
+            .iter()
+            .map(|(name, value)| (name.clone(), value_to_json(value, metadata)))
+            .collect::<serde_json::Map<_, _>>()
+            .into(),
+        Composite::Unnamed(values) => values
+            .iter()
+            .map(|value| value_to_json(value, metadata))
+            .collect(),
+    }
+}
+
+/// Is the given type a sequence or array of `u8`s? Other composites of `u8`s, like
+/// tuples, are left as they are.
+fn is_bytes_type(type_id: u32, metadata: &Metadata) -> bool {
+    let types = metadata.types();
+    let elem_type_id = match types.resolve(type_id).map(|ty| &ty.type_def) {
+        Some(TypeDef::Sequence(seq)) => seq.type_param.id,
+        Some(TypeDef::Array(arr)) => arr.type_param.id,
+        _ => return false,
+    };
+    matches!(
+        types.resolve(elem_type_id).map(|ty| &ty.type_def),
+        Some(TypeDef::Primitive(TypeDefPrimitive::U8))
+    )
+}
+
+fn primitive_to_json(primitive: &Primitive) -> serde_json::Value {
+    match primitive {
+        Primitive::Bool(b) => (*b).into(),
+        Primitive::Char(c) => c.to_string().into(),
+        Primitive::String(s) => s.clone().into(),
+        Primitive::U128(n) if *n <= MAX_SAFE_INTEGER => (*n as u64).into(),
+        Primitive::U128(n) => n.to_string().into(),
+        Primitive::I128(n) if n.unsigned_abs() <= MAX_SAFE_INTEGER => (*n as i64).into(),
+        Primitive::I128(n) => n.to_string().into(),
+        Primitive::U256(bytes) => primitive_types::U256::from_little_endian(bytes)
+            .to_string()
+            .into(),
+        Primitive::I256(bytes) => {
+            // Two's complement; negative numbers are written as their negated magnitude.
+            let n = primitive_types::U256::from_little_endian(bytes);
+            if n.bit(255) {
+                format!("-{}", (!n).overflowing_add(1.into()).0).into()
+            } else {
+                n.to_string().into()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn i256_to_json_is_signed_decimal() {
+        let i256 = |n: i128| {
+            let fill = if n < 0 { 0xff } else { 0 };
+            let mut bytes = [fill; 32];
+            bytes[..16].copy_from_slice(&n.to_le_bytes());
+            primitive_to_json(&Primitive::I256(bytes))
+        };
+
+        assert_eq!(i256(0), serde_json::json!("0"));
+        assert_eq!(i256(12345), serde_json::json!("12345"));
+        assert_eq!(i256(-1), serde_json::json!("-1"));
+        assert_eq!(i256(i128::MIN), serde_json::json!(i128::MIN.to_string()));
+
+        let mut min = [0; 32];
+        min[31] = 0x80;
+        assert_eq!(
+            primitive_to_json(&Primitive::I256(min)),
+            serde_json::json!(
+                "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
+            )
+        );
+    }
+}
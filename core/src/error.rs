@@ -6,6 +6,7 @@
 
 use alloc::boxed::Box;
 use alloc::string::String;
+use alloc::vec::Vec;
 use subxt_metadata::StorageHasher;
 use thiserror::Error as DeriveError;
 
@@ -99,6 +100,9 @@ pub enum MetadataError {
     /// Call not found.
     #[error("Call with name {0} not found")]
     CallNameNotFound(String),
+    /// Event not found.
+    #[error("Event with name {0} not found")]
+    EventNameNotFound(String),
     /// Runtime trait not found.
     #[error("Runtime trait with name {0} not found")]
     RuntimeTraitNotFound(String),
@@ -120,6 +124,9 @@ pub enum MetadataError {
     /// The generated interface used is not compatible with the node.
     #[error("The generated code is not compatible with the node")]
     IncompatibleCodegen,
+    /// The metadata differs from the expected metadata in the given pallets.
+    #[error("The metadata differs from the expected metadata in the following pallets: {}", .0.join(", "))]
+    MetadataMismatch(Vec<String>),
     /// Custom value not found.
     #[error("Custom value with name {0} not found")]
     CustomValueNameNotFound(String),
@@ -38,11 +38,15 @@
 //! }
 //! ```
 
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
 use alloc::sync::Arc;
 use alloc::vec::Vec;
 use codec::{Compact, Decode, Encode};
 use derive_where::derive_where;
 use scale_decode::{DecodeAsFields, DecodeAsType};
+use serde::{Deserialize, Serialize};
 use subxt_metadata::PalletMetadata;
 
 use crate::{error::MetadataError, Config, Error, Metadata};
@@ -83,6 +87,8 @@ pub struct Events<T: Config> {
     event_bytes: Arc<[u8]>,
     start_idx: usize,
     num_events: u32,
+    // Whether to decode the topics of each event, or just skip over them.
+    decode_topics: bool,
     marker: core::marker::PhantomData<T>,
 }
 
@@ -93,6 +99,7 @@ impl<T: Config> core::fmt::Debug for Events<T> {
             .field("event_bytes", &self.event_bytes)
             .field("start_idx", &self.start_idx)
             .field("num_events", &self.num_events)
+            .field("decode_topics", &self.decode_topics)
             .finish()
     }
 }
@@ -117,10 +124,24 @@ impl<T: Config> Events<T> {
             event_bytes: event_bytes.into(),
             start_idx,
             num_events,
+            decode_topics: true,
             marker: core::marker::PhantomData,
         }
     }
 
+    /// Configure whether the topics of each event are decoded when iterating over the events.
+    /// This is `true` by default. Topics which aren't decoded are skipped over without being
+    /// allocated, and [`EventDetails::topics()`] will return no topics for any event.
+    pub fn with_topics(mut self, decode_topics: bool) -> Self {
+        self.decode_topics = decode_topics;
+        self
+    }
+
+    /// The metadata that these events are decoded with.
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
     /// The number of events.
     pub fn len(&self) -> u32 {
         self.num_events
@@ -149,6 +170,7 @@ impl<T: Config> Events<T> {
         let event_bytes = self.event_bytes.clone();
         let metadata = self.metadata.clone();
         let num_events = self.num_events;
+        let decode_topics = self.decode_topics;
 
         let mut pos = self.start_idx;
         let mut index = 0;
@@ -156,7 +178,13 @@ impl<T: Config> Events<T> {
             if event_bytes.len() <= pos || num_events == index {
                 None
             } else {
-                match EventDetails::decode_from(metadata.clone(), event_bytes.clone(), pos, index) {
+                match EventDetails::decode_from(
+                    metadata.clone(),
+                    event_bytes.clone(),
+                    pos,
+                    index,
+                    decode_topics,
+                ) {
                     Ok(event_details) => {
                         // Skip over decoded bytes in next iteration:
                         pos += event_details.bytes().len();
@@ -187,6 +215,37 @@ impl<T: Config> Events<T> {
         })
     }
 
+    /// Iterate through the events using metadata to dynamically decode and skip
+    /// them, and return only those which should decode to the provided `Ev` type,
+    /// along with the [`Phase`] that each was emitted in. Events which aren't of the
+    /// given type are skipped over without being decoded into anything.
+    /// If an error occurs, all subsequent iterations return `None`.
+    pub fn find_with_phase<Ev: StaticEvent>(
+        &self,
+    ) -> impl Iterator<Item = Result<(Phase, Ev), Error>> + '_ {
+        self.iter().filter_map(|ev| {
+            ev.and_then(|ev| {
+                let phase = ev.phase();
+                ev.as_event::<Ev>().map(|e| e.map(|e| (phase, e)))
+            })
+            .transpose()
+        })
+    }
+
+    /// Iterate through the events using metadata to dynamically decode and skip
+    /// them, and return only those emitted by the pallet with the given name. The
+    /// fields of the returned events are not decoded into anything until asked for.
+    /// If an error occurs, all subsequent iterations return `None`.
+    pub fn find_pallet<'a>(
+        &'a self,
+        pallet_name: &'a str,
+    ) -> impl Iterator<Item = Result<EventDetails<T>, Error>> + 'a {
+        self.iter().filter(move |ev| match ev {
+            Ok(ev) => ev.pallet_name() == pallet_name,
+            Err(_) => true,
+        })
+    }
+
     /// Iterate through the events using metadata to dynamically decode and skip
     /// them, and return the first event found which decodes to the provided `Ev` type.
     pub fn find_first<Ev: StaticEvent>(&self) -> Result<Option<Ev>, Error> {
@@ -203,10 +262,43 @@ impl<T: Config> Events<T> {
     pub fn has<Ev: StaticEvent>(&self) -> Result<bool, Error> {
         Ok(self.find::<Ev>().next().transpose()?.is_some())
     }
+
+    /// Iterate over all of the events, grouping them by the [`Phase`] that they were
+    /// emitted in. See [`GroupedEvents`] for more. If an error occurs decoding any of
+    /// the events, it is returned.
+    pub fn group_by_phase(&self) -> Result<GroupedEvents<T>, Error> {
+        self.iter().collect()
+    }
+
+    /// Convert all of the events into a JSON array, where each event is converted
+    /// as per [`EventDetails::to_json()`].
+    pub fn to_json(&self) -> Result<serde_json::Value, Error> {
+        self.iter().map(|ev| ev?.to_json()).collect()
+    }
+}
+
+// Skip over a SCALE encoded vector of topics without allocating them.
+fn skip_topics<Hash: Decode>(input: &mut &[u8]) -> Result<(), codec::Error> {
+    let len = <Compact<u32>>::decode(input)?.0 as usize;
+    match Hash::encoded_fixed_size() {
+        Some(hash_size) => {
+            let topics_len = len
+                .checked_mul(hash_size)
+                .filter(|topics_len| *topics_len <= input.len())
+                .ok_or("Not enough bytes for the event topics")?;
+            *input = &input[topics_len..];
+        }
+        None => {
+            for _ in 0..len {
+                Hash::skip(input)?;
+            }
+        }
+    }
+    Ok(())
 }
 
 /// A phase of a block's execution.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Decode, Encode)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Decode, Encode, Serialize, Deserialize)]
 pub enum Phase {
     /// Applying an extrinsic.
     ApplyExtrinsic(u32),
@@ -216,6 +308,50 @@ pub enum Phase {
     Initialization,
 }
 
+/// Events grouped by the [`Phase`] that they were emitted in, as returned from
+/// [`Events::group_by_phase()`]. Within each group, events are in the order that they
+/// were emitted.
+#[derive(Debug, Clone)]
+pub struct GroupedEvents<T: Config> {
+    /// Events emitted while initializing the block.
+    pub initialization: Vec<EventDetails<T>>,
+    /// Events emitted while applying each extrinsic, keyed by the index of the extrinsic
+    /// in the block. Extrinsics which emitted no events have no entry.
+    pub extrinsics: BTreeMap<u32, Vec<EventDetails<T>>>,
+    /// Events emitted while finalizing the block.
+    pub finalization: Vec<EventDetails<T>>,
+}
+
+impl<T: Config> Default for GroupedEvents<T> {
+    fn default() -> Self {
+        GroupedEvents {
+            initialization: Vec::new(),
+            extrinsics: BTreeMap::new(),
+            finalization: Vec::new(),
+        }
+    }
+}
+
+impl<T: Config> Extend<EventDetails<T>> for GroupedEvents<T> {
+    fn extend<I: IntoIterator<Item = EventDetails<T>>>(&mut self, iter: I) {
+        for ev in iter {
+            match ev.phase() {
+                Phase::Initialization => self.initialization.push(ev),
+                Phase::ApplyExtrinsic(idx) => self.extrinsics.entry(idx).or_default().push(ev),
+                Phase::Finalization => self.finalization.push(ev),
+            }
+        }
+    }
+}
+
+impl<T: Config> FromIterator<EventDetails<T>> for GroupedEvents<T> {
+    fn from_iter<I: IntoIterator<Item = EventDetails<T>>>(iter: I) -> Self {
+        let mut grouped = GroupedEvents::default();
+        grouped.extend(iter);
+        grouped
+    }
+}
+
 /// The event details.
 #[derive(Debug, Clone)]
 pub struct EventDetails<T: Config> {
@@ -244,6 +380,7 @@ impl<T: Config> EventDetails<T> {
         all_bytes: Arc<[u8]>,
         start_idx: usize,
         index: u32,
+        decode_topics: bool,
     ) -> Result<EventDetails<T>, Error> {
         let input = &mut &all_bytes[start_idx..];
 
@@ -283,7 +420,12 @@ impl<T: Config> EventDetails<T> {
         let event_fields_end_idx = all_bytes.len() - input.len();
 
         // topics come after the event data in EventRecord.
-        let topics = Vec::<T::Hash>::decode(input)?;
+        let topics = if decode_topics {
+            Vec::<T::Hash>::decode(input)?
+        } else {
+            skip_topics::<T::Hash>(input)?;
+            Vec::new()
+        };
 
         // what bytes did we skip over in total, including topics.
         let end_idx = all_bytes.len() - input.len();
@@ -418,6 +560,95 @@ impl<T: Config> EventDetails<T> {
     pub fn topics(&self) -> &[T::Hash] {
         &self.topics
     }
+
+    /// Convert this event into a JSON object with `pallet`, `event`, `phase` and `fields`
+    /// keys. The phase is either `"Initialization"`, `"Finalization"` or an object like
+    /// `{ "ApplyExtrinsic": 1 }`, and the fields are converted as per
+    /// [`crate::dynamic::value_to_json()`].
+    pub fn to_json(&self) -> Result<serde_json::Value, Error> {
+        let phase = match self.phase {
+            Phase::ApplyExtrinsic(index) => serde_json::json!({ "ApplyExtrinsic": index }),
+            Phase::Finalization => "Finalization".into(),
+            Phase::Initialization => "Initialization".into(),
+        };
+        let fields = crate::dynamic::composite_to_json(&self.field_values()?, &self.metadata);
+        Ok(serde_json::json!({
+            "pallet": self.pallet_name(),
+            "event": self.variant_name(),
+            "phase": phase,
+            "fields": fields,
+        }))
+    }
+
+    /// Copy the details of this event into a [`RawEvent`], which can be serialized and
+    /// stored without the metadata needed to decode it.
+    pub fn to_raw(&self) -> RawEvent<T::Hash> {
+        RawEvent {
+            phase: self.phase,
+            pallet_name: self.pallet_name().into(),
+            variant_name: self.variant_name().into(),
+            data: self.field_bytes().to_vec(),
+            topics: self.topics.clone(),
+        }
+    }
+}
+
+/// The raw details of an event, as returned from [`EventDetails::to_raw()`]. Unlike
+/// [`EventDetails`], this does not hold on to any metadata, and so it can be serialized
+/// (with the field bytes as a hex string) and deserialized again.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RawEvent<Hash> {
+    /// The phase of the block that the event was emitted in.
+    pub phase: Phase,
+    /// The name of the pallet that the event belongs to.
+    pub pallet_name: String,
+    /// The name of the event variant.
+    pub variant_name: String,
+    /// The SCALE encoded fields of the event.
+    #[serde(with = "impl_serde::serialize")]
+    pub data: Vec<u8>,
+    /// The topics associated with the event.
+    pub topics: Vec<Hash>,
+}
+
+impl<Hash> RawEvent<Hash> {
+    /// Describe this event like `Balances::Transfer { from: .., to: .., amount: 10 }`, using
+    /// the given metadata to decode the fields of the event and find their names. This falls
+    /// back to the [`core::fmt::Display`] output if the event cannot be found in the metadata
+    /// or its fields cannot be decoded.
+    pub fn describe(&self, metadata: &Metadata) -> String {
+        let Some(variant) = metadata
+            .pallet_by_name(&self.pallet_name)
+            .and_then(|pallet| pallet.event_variant_by_name(&self.variant_name))
+        else {
+            return self.to_string();
+        };
+
+        let mut fields = variant
+            .fields
+            .iter()
+            .map(|f| scale_decode::Field::new(f.ty.id, f.name.as_deref()));
+        let bytes = &mut &*self.data;
+        match scale_value::scale::decode_as_fields(bytes, &mut fields, metadata.types()) {
+            Ok(values) if bytes.is_empty() => {
+                format!("{}::{} {values}", self.pallet_name, self.variant_name)
+            }
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl<Hash> core::fmt::Display for RawEvent<Hash> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}::{}(0x{})",
+            self.pallet_name,
+            self.variant_name,
+            hex::encode(&self.data)
+        )
+    }
 }
 
 /// Details for the given event plucked from the metadata.
@@ -708,6 +939,234 @@ mod tests {
         assert!(event_details.next().is_none());
     }
 
+    #[test]
+    fn decode_event_with_map_field() {
+        use alloc::collections::BTreeMap;
+
+        #[derive(Clone, Debug, PartialEq, Decode, Encode, TypeInfo)]
+        enum Event {
+            A(BTreeMap<u32, u128>, u8),
+            B(bool),
+        }
+
+        // The map decodes into anything with a compatible shape.
+        #[derive(Debug, PartialEq, scale_decode::DecodeAsType)]
+        struct A(Vec<(u32, u128)>, u8);
+
+        impl StaticEvent for A {
+            const PALLET: &'static str = "Test";
+            const EVENT: &'static str = "A";
+        }
+
+        // Create fake metadata that knows about our events, above:
+        let metadata = metadata::<Event>();
+
+        // A map is encoded as a length prefixed sequence of key-value tuples, which must
+        // be skipped over correctly in order to decode the event that follows it.
+        let map = BTreeMap::from([(1, 10), (2, u128::MAX), (300, 0)]);
+        let events = events::<Event>(
+            metadata,
+            vec![
+                event_record(Phase::ApplyExtrinsic(0), Event::A(map.clone(), 7)),
+                event_record(Phase::ApplyExtrinsic(1), Event::B(true)),
+            ],
+        );
+
+        let mut event_details = events.iter();
+        let a = event_details.next().unwrap().unwrap();
+        let entries = map.into_iter().collect();
+        assert_eq!(a.as_event::<A>().unwrap(), Some(A(entries, 7)));
+        let b = event_details.next().unwrap().unwrap();
+        assert_eq!(b.variant_name(), "B");
+        assert_eq!(
+            b.field_values().unwrap().map_context(|_| ()),
+            scale_value::Composite::unnamed(vec![Value::bool(true)])
+        );
+        assert!(event_details.next().is_none());
+    }
+
+    #[test]
+    fn invalid_option_variant_index_is_an_error() {
+        #[derive(Clone, Debug, PartialEq, Decode, Encode, TypeInfo)]
+        enum Event {
+            A(Option<u8>, bool),
+        }
+
+        // Create fake metadata that knows about our single event, above:
+        let metadata = metadata::<Event>();
+
+        // Encode an event record by hand, where the Option has a variant index of 2,
+        // which is neither None (0) nor Some (1):
+        let mut event_bytes = Phase::ApplyExtrinsic(0).encode();
+        event_bytes.extend([0, 0]); // Pallet index, event variant index
+        event_bytes.extend([2, 5, 1]); // Option variant index and value, bool
+        event_bytes.extend(Vec::<H256>::new().encode()); // Topics
+
+        let events = events_raw(metadata, event_bytes, 1);
+
+        let mut event_details = events.iter();
+        let err = event_details.next().unwrap().unwrap_err();
+        assert!(
+            matches!(err, Error::Decode(_)),
+            "expected a decode error but got {err:?}"
+        );
+        assert!(
+            err.to_string().contains("variant with index 2"),
+            "unexpected error: {err}"
+        );
+        assert!(event_details.next().is_none());
+    }
+
+    #[test]
+    fn raw_event_serde_roundtrip() {
+        #[derive(Clone, Debug, PartialEq, Decode, Encode, TypeInfo)]
+        enum Event {
+            A(u8, bool),
+        }
+
+        // Create fake metadata that knows about our events, above:
+        let metadata = metadata::<Event>();
+
+        let topic = H256::from([1; 32]);
+        let events = events::<Event>(
+            metadata,
+            vec![EventRecord::new(
+                Phase::ApplyExtrinsic(2),
+                Event::A(0xde, true),
+                vec![topic],
+            )],
+        );
+
+        let raw = events.iter().next().unwrap().unwrap().to_raw();
+        assert_eq!(
+            raw,
+            RawEvent {
+                phase: Phase::ApplyExtrinsic(2),
+                pallet_name: "Test".into(),
+                variant_name: "A".into(),
+                data: vec![0xde, 1],
+                topics: vec![topic],
+            }
+        );
+
+        let json = serde_json::to_value(&raw).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "phase": { "ApplyExtrinsic": 2 },
+                "palletName": "Test",
+                "variantName": "A",
+                "data": "0xde01",
+                "topics": [topic],
+            })
+        );
+        let decoded: RawEvent<H256> = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded, raw);
+    }
+
+    #[test]
+    fn raw_event_display_and_describe() {
+        #[derive(Clone, Debug, PartialEq, Decode, Encode, TypeInfo)]
+        enum Event {
+            A { amount: u8, keep_alive: bool },
+        }
+
+        // Create fake metadata that knows about our events, above:
+        let metadata = metadata::<Event>();
+
+        let events = events::<Event>(
+            metadata.clone(),
+            vec![event_record(
+                Phase::Initialization,
+                Event::A {
+                    amount: 10,
+                    keep_alive: true,
+                },
+            )],
+        );
+        let mut raw = events.iter().next().unwrap().unwrap().to_raw();
+
+        assert_eq!(raw.to_string(), "Test::A(0x0a01)");
+        assert_eq!(
+            raw.describe(&metadata),
+            "Test::A { amount: 10, keep_alive: true }"
+        );
+
+        // Events which can't be decoded with the metadata fall back to the Display output:
+        raw.data.push(0);
+        assert_eq!(raw.describe(&metadata), "Test::A(0x0a0100)");
+        raw.variant_name = "B".into();
+        assert_eq!(raw.describe(&metadata), "Test::B(0x0a0100)");
+    }
+
+    #[test]
+    fn events_to_json() {
+        #[derive(Clone, Debug, PartialEq, Decode, Encode, TypeInfo)]
+        enum Event {
+            A {
+                data: Vec<u8>,
+                small: u128,
+                big: u128,
+                list: Vec<u16>,
+                maybe: Option<u32>,
+                array: [u8; 2],
+                pair: (u8, u8),
+            },
+            B(bool),
+        }
+
+        // Create fake metadata that knows about our events, above:
+        let metadata = metadata::<Event>();
+
+        let events = events::<Event>(
+            metadata,
+            vec![
+                event_record(
+                    Phase::ApplyExtrinsic(1),
+                    Event::A {
+                        data: vec![0xde, 0xad],
+                        small: 10,
+                        big: u128::MAX,
+                        list: vec![1, 2],
+                        maybe: Some(3),
+                        array: [0xbe, 0xef],
+                        pair: (1, 2),
+                    },
+                ),
+                event_record(Phase::Finalization, Event::B(true)),
+            ],
+        );
+
+        assert_eq!(
+            events.to_json().unwrap(),
+            serde_json::json!([
+                {
+                    "pallet": "Test",
+                    "event": "A",
+                    "phase": { "ApplyExtrinsic": 1 },
+                    "fields": {
+                        // Bytes are hex encoded, but other sequences are not:
+                        "data": "0xdead",
+                        "small": 10,
+                        // Integers too large for JavaScript numbers become strings:
+                        "big": u128::MAX.to_string(),
+                        "list": [1, 2],
+                        "maybe": { "name": "Some", "values": [3] },
+                        "array": "0xbeef",
+                        // Only sequences and arrays of bytes are hex encoded:
+                        "pair": [1, 2],
+                    },
+                },
+                {
+                    "pallet": "Test",
+                    "event": "B",
+                    "phase": "Finalization",
+                    "fields": [true],
+                },
+            ])
+        );
+    }
+
     #[test]
     fn dynamically_decode_multiple_events() {
         #[derive(Clone, Copy, Debug, PartialEq, Decode, Encode, TypeInfo)]
@@ -909,6 +1368,107 @@ mod tests {
         assert!(event_details.next().is_none());
     }
 
+    #[test]
+    fn compact_u64_wrapper_followed_by_other_fields() {
+        #[derive(Clone, Decode, Debug, PartialEq, Encode, TypeInfo, scale_decode::DecodeAsType)]
+        enum Event {
+            A(#[codec(compact)] CompactWrapper, u8),
+        }
+
+        #[derive(
+            Clone,
+            Decode,
+            Debug,
+            PartialEq,
+            codec::CompactAs,
+            Encode,
+            TypeInfo,
+            scale_decode::DecodeAsType,
+        )]
+        struct CompactWrapper(u64);
+
+        // Create fake metadata that knows about our single event, above:
+        let metadata = metadata::<Event>();
+
+        // u64::MAX is the largest compact encoding a u64 can have; if we got the
+        // width of the inner primitive wrong we'd also misread the trailing u8.
+        let event = Event::A(CompactWrapper(u64::MAX), 8);
+        let events = events::<Event>(
+            metadata,
+            vec![event_record(Phase::Finalization, event.clone())],
+        );
+
+        let mut event_details = events.iter();
+        let ev = event_details.next().unwrap().unwrap();
+        assert_raw_events_match(
+            ev.clone(),
+            TestRawEventDetails {
+                index: 0,
+                phase: Phase::Finalization,
+                pallet: "Test".to_string(),
+                pallet_index: 0,
+                variant: "A".to_string(),
+                variant_index: 0,
+                fields: vec![
+                    Value::unnamed_composite(vec![Value::u128(u64::MAX as u128)]),
+                    Value::u128(8),
+                ],
+            },
+        );
+        assert!(event_details.next().is_none());
+
+        // And statically decode back into the concrete type:
+        let decoded = ev.as_root_event::<AllEvents<Event>>().unwrap();
+        assert_eq!(decoded, AllEvents::Test(event));
+    }
+
+    #[test]
+    fn bit_sequence_event_field() {
+        use bitvec::{bitvec, order::Lsb0, vec::BitVec};
+
+        #[derive(Clone, Debug, PartialEq, Decode, Encode, TypeInfo)]
+        enum Event {
+            A(BitVec<u8, Lsb0>, u8),
+        }
+
+        // Create fake metadata that knows about our single event, above:
+        let metadata = metadata::<Event>();
+
+        // Encode our events in the format we expect back from a node, and
+        // construct an Events object to iterate them. The bits span more than
+        // one store item, and the trailing u8 checks that we consume exactly
+        // the right number of bytes for the bit sequence:
+        let bits: BitVec<u8, Lsb0> = bitvec![u8, Lsb0; 1, 0, 1, 1, 0, 0, 1, 0, 1, 1];
+        let events = events::<Event>(
+            metadata,
+            vec![event_record(Phase::Finalization, Event::A(bits.clone(), 7))],
+        );
+
+        // Dynamically decode:
+        let mut event_details = events.iter();
+        let ev = event_details.next().unwrap().unwrap();
+        assert_raw_events_match(
+            ev.clone(),
+            TestRawEventDetails {
+                index: 0,
+                phase: Phase::Finalization,
+                pallet: "Test".to_string(),
+                pallet_index: 0,
+                variant: "A".to_string(),
+                variant_index: 0,
+                fields: vec![
+                    Value::bit_sequence(bits.iter().by_vals().collect()),
+                    Value::u128(7),
+                ],
+            },
+        );
+        assert!(event_details.next().is_none());
+
+        // The field bytes round trip back into the concrete bitvec type:
+        let decoded = <(BitVec<u8, Lsb0>, u8)>::decode(&mut ev.field_bytes()).unwrap();
+        assert_eq!(decoded, (bits, 7));
+    }
+
     #[test]
     fn event_containing_explicit_index() {
         #[derive(Clone, Debug, PartialEq, Eq, Decode, Encode, TypeInfo)]
@@ -981,4 +1541,181 @@ mod tests {
 
         assert_eq!(topics, ev.topics());
     }
+
+    #[test]
+    fn skipping_topics() {
+        #[derive(Clone, Debug, PartialEq, Decode, Encode, TypeInfo)]
+        enum Event {
+            A(u8),
+        }
+
+        // Create fake metadata that knows about our single event, above:
+        let metadata = metadata::<Event>();
+
+        let topics = vec![H256::from_low_u64_le(123), H256::from_low_u64_le(456)];
+        let events = events::<Event>(
+            metadata,
+            vec![
+                EventRecord::new(Phase::Initialization, Event::A(1), topics.clone()),
+                EventRecord::new(Phase::Finalization, Event::A(2), topics),
+            ],
+        )
+        .with_topics(false);
+
+        // The topics are skipped over exactly, so the next event decodes fine:
+        let evs: Vec<_> = events
+            .iter()
+            .collect::<Result<_, _>>()
+            .expect("events should decode OK");
+        assert_eq!(evs.len(), 2);
+        assert_eq!(evs[1].phase(), Phase::Finalization);
+        assert_eq!(evs[1].field_bytes(), &[2]);
+        assert!(evs.iter().all(|ev| ev.topics().is_empty()));
+    }
+
+    #[test]
+    fn find_with_phase() {
+        #[derive(Clone, Debug, PartialEq, Decode, Encode, TypeInfo)]
+        enum Event {
+            A(u8),
+            B(u32, bool),
+        }
+
+        #[derive(Debug, PartialEq, scale_decode::DecodeAsType)]
+        struct B(u32, bool);
+
+        impl StaticEvent for B {
+            const PALLET: &'static str = "Test";
+            const EVENT: &'static str = "B";
+        }
+
+        // Create fake metadata that knows about our events, above:
+        let metadata = metadata::<Event>();
+
+        // Encode our events in the format we expect back from a node, and
+        // construct an Events object to iterate them:
+        let events = events::<Event>(
+            metadata,
+            vec![
+                event_record(Phase::Initialization, Event::B(1, true)),
+                event_record(Phase::ApplyExtrinsic(0), Event::A(2)),
+                event_record(Phase::ApplyExtrinsic(1), Event::B(3, false)),
+                event_record(Phase::Finalization, Event::A(4)),
+            ],
+        );
+
+        let found: Vec<(Phase, B)> = events
+            .find_with_phase::<B>()
+            .collect::<Result<_, _>>()
+            .expect("events should decode OK");
+
+        assert_eq!(
+            found,
+            vec![
+                (Phase::Initialization, B(1, true)),
+                (Phase::ApplyExtrinsic(1), B(3, false)),
+            ]
+        );
+    }
+
+    #[test]
+    fn group_by_phase() {
+        #[derive(Clone, Debug, PartialEq, Decode, Encode, TypeInfo)]
+        enum Event {
+            A(u8),
+        }
+
+        // Create fake metadata that knows about our events, above:
+        let metadata = metadata::<Event>();
+
+        let events = events::<Event>(
+            metadata,
+            vec![
+                event_record(Phase::Initialization, Event::A(1)),
+                event_record(Phase::ApplyExtrinsic(0), Event::A(2)),
+                event_record(Phase::ApplyExtrinsic(2), Event::A(3)),
+                event_record(Phase::ApplyExtrinsic(0), Event::A(4)),
+                event_record(Phase::Finalization, Event::A(5)),
+            ],
+        );
+
+        let grouped = events.group_by_phase().expect("events should decode OK");
+        let indexes = |evs: &[EventDetails<SubstrateConfig>]| -> Vec<u32> {
+            evs.iter().map(|ev| ev.index()).collect()
+        };
+
+        assert_eq!(indexes(&grouped.initialization), vec![0]);
+        assert_eq!(
+            grouped
+                .extrinsics
+                .iter()
+                .map(|(idx, evs)| (*idx, indexes(evs)))
+                .collect::<Vec<_>>(),
+            vec![(0, vec![1, 3]), (2, vec![2])]
+        );
+        assert_eq!(indexes(&grouped.finalization), vec![4]);
+    }
+
+    #[test]
+    fn find_pallet() {
+        #[derive(Clone, Debug, PartialEq, Decode, Encode, TypeInfo)]
+        enum Event {
+            A(u8),
+            B(u32, bool),
+        }
+
+        // Create fake metadata that knows about our events, above:
+        let metadata = metadata::<Event>();
+
+        let events = events::<Event>(
+            metadata,
+            vec![
+                event_record(Phase::Initialization, Event::B(1, true)),
+                event_record(Phase::ApplyExtrinsic(0), Event::A(2)),
+            ],
+        );
+
+        let found: Vec<String> = events
+            .find_pallet("Test")
+            .map(|ev| ev.map(|ev| ev.variant_name().to_owned()))
+            .collect::<Result<_, _>>()
+            .expect("events should decode OK");
+        assert_eq!(found, vec!["B".to_owned(), "A".to_owned()]);
+
+        assert_eq!(events.find_pallet("Other").count(), 0);
+    }
+
+    #[test]
+    fn event_metadata_by_name() {
+        #[derive(Clone, Debug, PartialEq, Decode, Encode, TypeInfo, scale_decode::DecodeAsType)]
+        enum Event {
+            A(u8),
+            #[codec(index = 5)]
+            B(bool),
+        }
+
+        let metadata = metadata::<Event>();
+
+        let details = metadata.event_by_name("Test", "B").unwrap();
+        assert_eq!(details.pallet.index(), 0);
+        assert_eq!(details.variant.index, 5);
+
+        // The indexes line up with those of decoded events.
+        let events = events::<Event>(
+            metadata.clone(),
+            vec![event_record(Phase::Finalization, Event::B(true))],
+        );
+        let ev = events.iter().next().unwrap().unwrap();
+        assert_eq!(ev.pallet_index(), details.pallet.index());
+        assert_eq!(ev.variant_index(), details.variant.index);
+
+        assert!(matches!(
+            metadata.event_by_name("Test", "C"),
+            Err(crate::error::MetadataError::EventNameNotFound(name)) if name == "C"
+        ));
+        assert!(matches!(
+            metadata.event_by_name("NotAPallet", "B"),
+            Err(crate::error::MetadataError::PalletNameNotFound(_))
+        ));
+    }
 }
@@ -3,6 +3,7 @@
 // see LICENSE for license details.
 
 use crate::error::MetadataError;
+use crate::events::EventMetadataDetails;
 
 use alloc::borrow::ToOwned;
 use alloc::sync::Arc;
@@ -39,6 +40,20 @@ impl Metadata {
             .ok_or(MetadataError::PalletIndexNotFound(index))
     }
 
+    /// Look up the metadata for an event given the name of its pallet and its own name. This
+    /// makes it possible to find out the pallet and variant indexes of an event up front.
+    pub fn event_by_name(
+        &self,
+        pallet: &str,
+        event: &str,
+    ) -> Result<EventMetadataDetails, MetadataError> {
+        let pallet = self.pallet_by_name_err(pallet)?;
+        let variant = pallet
+            .event_variant_by_name(event)
+            .ok_or_else(|| MetadataError::EventNameNotFound(event.to_owned()))?;
+        Ok(EventMetadataDetails { pallet, variant })
+    }
+
     /// Identical to `metadata.runtime_api_trait_by_name()`, but returns an error if the trait is not found.
     pub fn runtime_api_trait_by_name_err(
         &self,
@@ -19,6 +19,9 @@
 mod decode_encode_traits;
 mod metadata_type;
 
+use crate::error::MetadataError;
+use alloc::borrow::ToOwned;
+use alloc::vec::Vec;
 use codec::Decode;
 
 pub use decode_encode_traits::{DecodeWithMetadata, EncodeWithMetadata};
@@ -32,3 +35,61 @@ pub use metadata_type::Metadata;
 pub fn decode_from(bytes: &[u8]) -> Result<Metadata, codec::Error> {
     Metadata::decode(&mut &*bytes)
 }
+
+/// Check that the given [`Metadata`] (for instance, from the node we are connected to) is
+/// compatible with some `expected` metadata (for instance, the metadata that some code was
+/// generated from), returning [`MetadataError::MetadataMismatch`] with the names of any
+/// pallets in the expected metadata which are missing or differ in shape if not.
+///
+/// Pallets which only exist in the given metadata are ignored.
+pub fn validate(metadata: &Metadata, expected: &Metadata) -> Result<(), MetadataError> {
+    let mismatched_pallets: Vec<_> = expected
+        .pallets()
+        .filter(|expected_pallet| {
+            metadata
+                .pallet_by_name(expected_pallet.name())
+                .map_or(true, |pallet| pallet.hash() != expected_pallet.hash())
+        })
+        .map(|pallet| pallet.name().to_owned())
+        .collect();
+
+    if mismatched_pallets.is_empty() {
+        Ok(())
+    } else {
+        Err(MetadataError::MetadataMismatch(mismatched_pallets))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn load_metadata(bytes: &[u8]) -> Metadata {
+        decode_from(bytes).unwrap()
+    }
+
+    #[test]
+    fn validate_reports_mismatched_pallets() {
+        let full = load_metadata(include_bytes!(
+            "../../../artifacts/polkadot_metadata_full.scale"
+        ));
+        let small = load_metadata(include_bytes!(
+            "../../../artifacts/polkadot_metadata_small.scale"
+        ));
+
+        // The small metadata is a subset of the full metadata:
+        validate(&full, &small).unwrap();
+
+        // But not the other way around:
+        let Err(MetadataError::MetadataMismatch(pallets)) = validate(&small, &full) else {
+            panic!("expected a metadata mismatch");
+        };
+        let missing_pallets: Vec<_> = full
+            .pallets()
+            .filter(|p| small.pallet_by_name(p.name()).is_none())
+            .map(|p| p.name().to_owned())
+            .collect();
+        assert!(!missing_pallets.is_empty());
+        assert_eq!(pallets, missing_pallets);
+    }
+}
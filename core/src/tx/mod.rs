@@ -52,6 +52,20 @@
 //!
 //! // And log it:
 //! println!("Tx: 0x{}", hex::encode(signed_call.encoded()));
+//!
+//! // If signing happens elsewhere (for instance on a hardware wallet), we can instead
+//! // build a partial transaction, hand out the bytes to be signed, and then attach the
+//! // address and signature that we get back:
+//! let params = Params::new().tip(1_000).nonce(0).build();
+//! let partial_call = tx::create_partial_signed(&call, &state, params).unwrap();
+//! let signer_payload = partial_call.signer_payload();
+//!
+//! let signature = dev::alice().sign(&signer_payload);
+//! let public_key = dev::alice().public_key();
+//! # assert!(subxt_signer::sr25519::verify(&signature, &signer_payload, &public_key));
+//! let address = public_key.into();
+//! let signed_call = partial_call.sign_with_address_and_signature(&address, &signature.into());
+//! # assert!(signed_call.encoded().ends_with(partial_call.call_data()));
 //! ```
 
 pub mod payload;
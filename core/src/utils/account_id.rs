@@ -51,15 +51,38 @@ impl From<[u8; 32]> for AccountId32 {
 }
 
 impl AccountId32 {
-    // Return the ss58-check string for this key. Adapted from `sp_core::crypto`. We need this to
-    // serialize our account appropriately but otherwise don't care.
+    // Return the ss58-check string for this key. We need this to serialize our account
+    // appropriately but otherwise don't care.
     fn to_ss58check(&self) -> String {
         // For serializing to a string to obtain the account nonce, we use the default substrate
         // prefix (since we have no way to otherwise pick one). It doesn't really matter, since when
         // it's deserialized back in system_accountNextIndex, we ignore this (so long as it's valid).
-        const SUBSTRATE_SS58_PREFIX: u8 = 42;
-        // prefix <= 63 just take up one byte at the start:
-        let mut v = vec![SUBSTRATE_SS58_PREFIX];
+        self.to_ss58check_with_version(SUBSTRATE_SS58_PREFIX)
+    }
+
+    /// Return the SS58 address string for this key, using the given address prefix. Different
+    /// chains use different prefixes (for instance, 0 for Polkadot and 2 for Kusama); a chain
+    /// reports the prefix that it uses via the `ss58Format` field of its system properties.
+    ///
+    /// Only the lower 14 bits of the prefix are used, since valid prefixes are in the range
+    /// `0..=16383`.
+    // Adapted from `sp_core::crypto::Ss58Codec::to_ss58check_with_version`.
+    pub fn to_ss58check_with_version(&self, prefix: u16) -> String {
+        // We mask out the upper two bits of the ident - SS58 Prefix currently only supports 14-bits
+        let ident = prefix & 0b0011_1111_1111_1111;
+        let mut v = match ident {
+            // prefix <= 63 just take up one byte at the start:
+            0..=63 => vec![ident as u8],
+            // prefix <= 16383 take up two bytes:
+            _ => {
+                // upper six bits of the lower byte(!)
+                let first = ((ident & 0b0000_0000_1111_1100) as u8) >> 2;
+                // lower two bits of the lower byte in the high pos,
+                // lower bits of the upper byte in the low pos
+                let second = ((ident >> 8) as u8) | ((ident & 0b0000_0000_0000_0011) as u8) << 6;
+                vec![first | 0b01000000, second]
+            }
+        };
         // then push the account ID bytes.
         v.extend(self.0);
         // then push a 2 byte checksum of what we have so far.
@@ -104,6 +127,9 @@ impl AccountId32 {
     }
 }
 
+/// The generic Substrate SS58 address prefix, used by chains which don't have their own.
+pub const SUBSTRATE_SS58_PREFIX: u16 = 42;
+
 /// An error obtained from trying to interpret an SS58 encoded string into an AccountId32
 #[derive(Clone, Copy, Eq, PartialEq, Debug, DeriveError)]
 #[allow(missing_docs)]
@@ -221,4 +247,23 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn ss58_with_version_is_compatible_with_substrate_impl() {
+        use sp_core::crypto::Ss58AddressFormat;
+
+        let substrate_account = AccountKeyring::Alice.to_account_id();
+        let local_account = AccountId32(substrate_account.clone().into());
+
+        // Single byte prefixes (Polkadot, Kusama, Substrate) as well as two byte ones:
+        for prefix in [0, 2, 42, 63, 64, 1284, 16383] {
+            let substrate_ss58 =
+                substrate_account.to_ss58check_with_version(Ss58AddressFormat::custom(prefix));
+            assert_eq!(
+                substrate_ss58,
+                local_account.to_ss58check_with_version(prefix),
+                "prefix {prefix}"
+            );
+        }
+    }
 }
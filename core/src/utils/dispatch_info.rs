@@ -0,0 +1,160 @@
+// Copyright 2019-2024 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use codec::Decode;
+use core::marker::PhantomData;
+use scale_decode::{visitor::types::Composite, DecodeAsType, IntoVisitor, TypeResolver, Visitor};
+
+/// The weight of an extrinsic. Runtimes which predate two dimensional weights only
+/// report a `ref_time`, in which case the `proof_size` is 0.
+///
+/// When decoded via [`scale_decode::DecodeAsType`], both the older `u64` weights and the
+/// newer `{ ref_time, proof_size }` weights are accepted and normalized into this form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Decode)]
+pub struct Weight {
+    /// The computational time used to execute some logic, in picoseconds.
+    #[codec(compact)]
+    pub ref_time: u64,
+    /// The size of the proof needed to execute some logic, in bytes.
+    #[codec(compact)]
+    pub proof_size: u64,
+}
+
+impl Weight {
+    /// Construct a weight from its parts.
+    pub const fn from_parts(ref_time: u64, proof_size: u64) -> Self {
+        Weight {
+            ref_time,
+            proof_size,
+        }
+    }
+
+    /// The computational time used to execute some logic, in picoseconds.
+    pub const fn ref_time(&self) -> u64 {
+        self.ref_time
+    }
+
+    /// The size of the proof needed to execute some logic, in bytes.
+    pub const fn proof_size(&self) -> u64 {
+        self.proof_size
+    }
+}
+
+/// Decodes a [`Weight`] from either a `u64` (or a type wrapping one), or a composite type
+/// with `ref_time` and `proof_size` fields.
+#[doc(hidden)]
+pub struct WeightVisitor<R>(PhantomData<R>);
+
+impl<R: TypeResolver> Visitor for WeightVisitor<R> {
+    type Value<'scale, 'resolver> = Weight;
+    type Error = scale_decode::Error;
+    type TypeResolver = R;
+
+    fn visit_u64<'scale, 'resolver>(
+        self,
+        value: u64,
+        _type_id: R::TypeId,
+    ) -> Result<Self::Value<'scale, 'resolver>, Self::Error> {
+        Ok(Weight::from_parts(value, 0))
+    }
+
+    fn visit_composite<'scale, 'resolver>(
+        self,
+        value: &mut Composite<'scale, 'resolver, R>,
+        _type_id: R::TypeId,
+    ) -> Result<Self::Value<'scale, 'resolver>, Self::Error> {
+        // Older weights may be a u64 wrapped in a single field type.
+        if value.remaining() == 1 && value.has_unnamed_fields() {
+            let field = value.next().expect("length checked")?;
+            return field.decode_as_type::<Weight>();
+        }
+
+        let mut weight = Weight::default();
+        for field in value {
+            let field = field?;
+            match field.name() {
+                Some("ref_time") => weight.ref_time = field.decode_as_type()?,
+                Some("proof_size") => weight.proof_size = field.decode_as_type()?,
+                _ => {}
+            }
+        }
+        Ok(weight)
+    }
+}
+
+impl IntoVisitor for Weight {
+    type AnyVisitor<R: TypeResolver> = WeightVisitor<R>;
+    fn into_visitor<R: TypeResolver>() -> WeightVisitor<R> {
+        WeightVisitor(PhantomData)
+    }
+}
+
+/// The class of an extrinsic, which determines how it is prioritised and which block
+/// weight limits apply to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Decode, DecodeAsType)]
+pub enum DispatchClass {
+    /// A normal extrinsic.
+    Normal,
+    /// An operational extrinsic.
+    Operational,
+    /// A mandatory extrinsic, which is always included regardless of its weight.
+    Mandatory,
+}
+
+/// Information about the dispatch of an extrinsic, as reported in the
+/// `System.ExtrinsicSuccess` and `System.ExtrinsicFailed` events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, DecodeAsType)]
+pub struct DispatchInfo {
+    /// The weight consumed by the extrinsic.
+    pub weight: Weight,
+    /// The class of the extrinsic.
+    pub class: DispatchClass,
+    /// Whether the extrinsic pays fees.
+    pub pays_fee: Pays,
+}
+
+/// Whether an extrinsic pays fees.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, DecodeAsType)]
+pub enum Pays {
+    /// The extrinsic pays fees.
+    Yes,
+    /// The extrinsic does not pay fees.
+    No,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use codec::{Compact, Encode};
+
+    #[test]
+    fn weight_decodes_from_v1_and_v2_shapes() {
+        #[derive(scale_info::TypeInfo)]
+        struct WeightV1Wrapped(#[allow(dead_code)] u64);
+
+        #[derive(scale_info::TypeInfo)]
+        #[allow(dead_code)]
+        struct WeightV2 {
+            #[codec(compact)]
+            ref_time: u64,
+            #[codec(compact)]
+            proof_size: u64,
+        }
+
+        fn decode<T: scale_info::TypeInfo + 'static>(bytes: Vec<u8>) -> Weight {
+            let mut types = scale_info::Registry::new();
+            let id = types.register_type(&scale_info::meta_type::<T>()).id;
+            let types: scale_info::PortableRegistry = types.into();
+            Weight::decode_as_type(&mut &*bytes, id, &types).unwrap()
+        }
+
+        assert_eq!(decode::<u64>(10u64.encode()), Weight::from_parts(10, 0));
+        assert_eq!(
+            decode::<WeightV1Wrapped>(10u64.encode()),
+            Weight::from_parts(10, 0)
+        );
+        let weight = decode::<WeightV2>((Compact(10u64), Compact(20u64)).encode());
+        assert_eq!((weight.ref_time(), weight.proof_size()), (10, 20));
+    }
+}
@@ -2,7 +2,9 @@
 // This file is dual-licensed as Apache-2.0 or GPL-3.0.
 // see LICENSE for license details.
 
-use scale_decode::DecodeAsType;
+use alloc::{string::ToString, vec};
+use core::marker::PhantomData;
+use scale_decode::{error::ErrorKind, visitor::types::Variant, IntoVisitor, TypeResolver, Visitor};
 use scale_encode::EncodeAsType;
 
 // Dev note: This and related bits taken from `sp_runtime::generic::Era`
@@ -16,7 +18,6 @@ use scale_encode::EncodeAsType;
     Debug,
     serde::Serialize,
     serde::Deserialize,
-    DecodeAsType,
     EncodeAsType,
     scale_info::TypeInfo,
 )]
@@ -105,3 +106,88 @@ impl codec::Decode for Era {
         }
     }
 }
+
+/// Decodes an [`Era`] from the type that runtimes use, which has an `Immortal` variant and
+/// a `Mortal1` to `Mortal255` variant for each possible first byte of a mortal era, or from
+/// the type of [`Era`] itself.
+#[doc(hidden)]
+pub struct EraVisitor<R>(PhantomData<R>);
+
+impl<R: TypeResolver> Visitor for EraVisitor<R> {
+    type Value<'scale, 'resolver> = Era;
+    type Error = scale_decode::Error;
+    type TypeResolver = R;
+
+    fn visit_variant<'scale, 'resolver>(
+        self,
+        value: &mut Variant<'scale, 'resolver, R>,
+        _type_id: R::TypeId,
+    ) -> Result<Self::Value<'scale, 'resolver>, Self::Error> {
+        match value.name() {
+            "Immortal" => Ok(Era::Immortal),
+            "Mortal" => {
+                let (mut period, mut phase) = (0, 0);
+                for field in value.fields() {
+                    let field = field?;
+                    match field.name() {
+                        Some("period") => period = field.decode_as_type()?,
+                        Some("phase") => phase = field.decode_as_type()?,
+                        _ => {}
+                    }
+                }
+                Ok(Era::Mortal { period, phase })
+            }
+            // The variant index is the first byte of the SCALE encoded era, and so the bytes
+            // of the variant are the SCALE encoded era.
+            name if name.starts_with("Mortal") => {
+                codec::Decode::decode(&mut value.bytes_from_start())
+                    .map_err(|_| scale_decode::Error::custom_str("Invalid period and phase"))
+            }
+            name => Err(scale_decode::Error::new(ErrorKind::CannotFindVariant {
+                got: name.to_string(),
+                expected: vec!["Immortal", "Mortal"],
+            })),
+        }
+    }
+}
+
+impl IntoVisitor for Era {
+    type AnyVisitor<R: TypeResolver> = EraVisitor<R>;
+    fn into_visitor<R: TypeResolver>() -> EraVisitor<R> {
+        EraVisitor(PhantomData)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use codec::{Decode, Encode};
+    use polkadot_sdk::sp_runtime::generic::Era as SpEra;
+
+    #[test]
+    fn mortal_era_encoding_matches_sp_runtime() {
+        let cases = [
+            // (period, current block)
+            (4, 0),
+            (64, 42),
+            (64, 1_000_000),
+            (100, 12_345), // period is rounded up to the next power of two
+            (1, 7),        // period is clamped to at least 4
+            (1 << 16, 9_876_543),
+            (u64::MAX, 5), // period is clamped to at most 1 << 16
+        ];
+
+        for (period, current) in cases {
+            let era = Era::mortal(period, current);
+            let sp_era = SpEra::mortal(period, current);
+            assert_eq!(
+                era.encode(),
+                sp_era.encode(),
+                "encoding differs for period {period}, current {current}"
+            );
+            assert_eq!(Era::decode(&mut &*era.encode()).unwrap(), era);
+        }
+
+        assert_eq!(Era::Immortal.encode(), SpEra::Immortal.encode());
+    }
+}
@@ -7,6 +7,7 @@
 mod account_id;
 mod account_id20;
 pub mod bits;
+mod dispatch_info;
 mod era;
 mod multi_address;
 mod multi_signature;
@@ -21,8 +22,9 @@ use alloc::vec::Vec;
 use codec::{Compact, Decode, Encode};
 use derive_where::derive_where;
 
-pub use account_id::AccountId32;
+pub use account_id::{AccountId32, SUBSTRATE_SS58_PREFIX};
 pub use account_id20::AccountId20;
+pub use dispatch_info::{DispatchClass, DispatchInfo, Pays, Weight};
 pub use era::Era;
 pub use multi_address::MultiAddress;
 pub use multi_signature::MultiSignature;
@@ -36,6 +38,26 @@ pub use wrapper_opaque::WrapperKeepOpaque;
 #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Encoded(pub Vec<u8>);
 
+impl Encoded {
+    /// SCALE encode the given value and wrap the resulting bytes. Prefer this to constructing
+    /// [`Encoded`] from bytes directly, which makes it easy to accidentally wrap bytes that
+    /// have been encoded differently to how the value would be (for instance, with an extra
+    /// length prefix from encoding them as a `Vec<u8>`).
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, this panics if the value encodes to no bytes at all, since wrapping
+    /// such a value is almost certainly a mistake.
+    pub fn from_value<E: Encode>(value: &E) -> Self {
+        let bytes = value.encode();
+        debug_assert!(
+            !bytes.is_empty(),
+            "Encoded::from_value was given a value which encodes to no bytes"
+        );
+        Encoded(bytes)
+    }
+}
+
 impl codec::Encode for Encoded {
     fn encode(&self) -> Vec<u8> {
         self.0.to_owned()
@@ -80,3 +102,24 @@ pub struct Yes;
 pub fn to_hex(bytes: impl AsRef<[u8]>) -> String {
     format!("0x{}", hex::encode(bytes.as_ref()))
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn encoded_from_value_is_not_length_prefixed() {
+        let value = (1u8, alloc::vec![2u8, 3u8]);
+        let encoded = Encoded::from_value(&value);
+        assert_eq!(encoded.0, value.encode());
+        // Wrapping the bytes does not add another length prefix when they are encoded:
+        assert_eq!(encoded.encode(), alloc::vec![1, 8, 2, 3]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn encoded_from_value_panics_on_empty_bytes() {
+        Encoded::from_value(&());
+    }
+}
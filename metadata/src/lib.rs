@@ -314,6 +314,13 @@ impl<'a> PalletMetadata<'a> {
         )
     }
 
+    /// Return an event variant given it's name.
+    pub fn event_variant_by_name(&self, event_name: &str) -> Option<&'a Variant<PortableForm>> {
+        self.inner
+            .event_variant_index
+            .lookup_by_name(event_name, self.inner.event_ty, self.types)
+    }
+
     /// Return all of the call variants, if a call type exists.
     pub fn call_variants(&self) -> Option<&'a [Variant<PortableForm>]> {
         VariantIndex::get(self.inner.call_ty, self.types)
@@ -372,6 +379,11 @@ impl<'a> PalletMetadata<'a> {
         crate::utils::validation::get_call_hash(self, call_name)
     }
 
+    /// Return a hash for the event, or None if it was not found.
+    pub fn event_hash(&self, event_name: &str) -> Option<[u8; HASH_LEN]> {
+        crate::utils::validation::get_event_hash(self, event_name)
+    }
+
     /// Return a hash for the entire pallet.
     pub fn hash(&self) -> [u8; HASH_LEN] {
         crate::utils::validation::get_pallet_hash(*self, &OuterEnumHashes::empty())
@@ -904,4 +916,16 @@ mod test {
         // The bytes should be identical:
         assert_eq!(bytes, new_bytes);
     }
+
+    #[test]
+    fn event_variant_lookups_agree() {
+        let metadata = Metadata::decode(&mut &*load_metadata()).unwrap();
+        let balances = metadata.pallet_by_name("Balances").unwrap();
+
+        let by_name = balances.event_variant_by_name("Transfer").unwrap();
+        let by_index = balances.event_variant_by_index(by_name.index).unwrap();
+        assert_eq!(by_name, by_index);
+
+        assert!(balances.event_variant_by_name("NotAnEvent").is_none());
+    }
 }
@@ -474,6 +474,20 @@ pub fn get_call_hash(pallet: &PalletMetadata, call_name: &str) -> Option<Hash> {
     Some(hash)
 }
 
+/// Obtain the hash for a specific event, or an error if it's not found.
+pub fn get_event_hash(pallet: &PalletMetadata, event_name: &str) -> Option<Hash> {
+    let event_variant = pallet.event_variant_by_name(event_name)?;
+
+    // hash the specific variant representing the event we are interested in.
+    let hash = get_variant_hash(
+        pallet.types,
+        event_variant,
+        &mut HashMap::new(),
+        &OuterEnumHashes::empty(),
+    );
+    Some(hash)
+}
+
 /// Obtain the hash of a specific runtime API function, or an error if it's not found.
 pub fn get_runtime_api_hash(runtime_apis: &RuntimeApiMetadata, method_name: &str) -> Option<Hash> {
     let trait_name = &*runtime_apis.inner.name;
@@ -1248,4 +1262,42 @@ mod tests {
 
         assert_eq!(hash, hash_trimmed);
     }
+
+    #[test]
+    fn event_hash_only_changes_with_event_fields() {
+        #[allow(dead_code)]
+        #[derive(scale_info::TypeInfo)]
+        enum EventsV1 {
+            Transfer { amount: u64 },
+            Remarked,
+        }
+
+        #[allow(dead_code)]
+        #[derive(scale_info::TypeInfo)]
+        enum EventsV2 {
+            Transfer { amount: u128 },
+            Remarked,
+        }
+
+        let metadata_with_events = |ty| {
+            pallets_to_metadata(vec![v15::PalletMetadata {
+                event: Some(v15::PalletEventMetadata { ty }),
+                ..default_pallet()
+            }])
+        };
+        let metadata_1 = metadata_with_events(meta_type::<EventsV1>());
+        let metadata_2 = metadata_with_events(meta_type::<EventsV2>());
+        let pallet_1 = metadata_1.pallet_by_name("Test").unwrap();
+        let pallet_2 = metadata_2.pallet_by_name("Test").unwrap();
+
+        assert_ne!(
+            pallet_1.event_hash("Transfer").unwrap(),
+            pallet_2.event_hash("Transfer").unwrap()
+        );
+        assert_eq!(
+            pallet_1.event_hash("Remarked").unwrap(),
+            pallet_2.event_hash("Remarked").unwrap()
+        );
+        assert!(pallet_1.event_hash("NotAnEvent").is_none());
+    }
 }
@@ -1034,4 +1034,33 @@ mod test {
         });
         let _ = serde_json::from_value::<Initialized<String>>(event).unwrap_err();
     }
+
+    #[test]
+    fn transaction_finalized_has_block_hash_and_index() {
+        let event = serde_json::json!({
+            "event": "finalized",
+            "block": { "hash": "0x1", "index": "3" },
+        });
+        let decoded: TransactionStatus<String> = serde_json::from_value(event).unwrap();
+        assert_eq!(
+            decoded,
+            TransactionStatus::Finalized {
+                block: TransactionBlockDetails {
+                    hash: "0x1".to_string(),
+                    index: 3
+                }
+            }
+        );
+
+        // A transaction that's been dropped from the best chain has no block:
+        let event = serde_json::json!({
+            "event": "bestChainBlockIncluded",
+            "block": null,
+        });
+        let decoded: TransactionStatus<String> = serde_json::from_value(event).unwrap();
+        assert_eq!(
+            decoded,
+            TransactionStatus::BestChainBlockIncluded { block: None }
+        );
+    }
 }
@@ -434,16 +434,21 @@ where
         // Iterate over all of the previous blocks we need headers for, ignoring the current block
         // (which we already have the header info for):
         let methods = methods.clone();
-        let previous_headers = stream::iter(start_block_num..end_block_num)
-            .then(move |n| {
-                let methods = methods.clone();
-                async move {
-                    let hash = methods.chain_get_block_hash(Some(n.into())).await?;
-                    let header = methods.chain_get_header(hash).await?;
-                    Ok::<_, Error>(header)
-                }
-            })
-            .filter_map(|h| async { h.transpose() });
+        let previous_headers = stream::iter(start_block_num..end_block_num).then(move |n| {
+            let methods = methods.clone();
+            async move {
+                // A block missing from the gap is reported rather than skipped, so that
+                // the caller knows it has not been handed every block.
+                let hash = methods
+                    .chain_get_block_hash(Some(n.into()))
+                    .await?
+                    .ok_or_else(|| Error::Other(format!("No block hash found for block #{n}")))?;
+                methods
+                    .chain_get_header(Some(hash))
+                    .await?
+                    .ok_or_else(|| Error::Other(format!("No header found for block #{n}")))
+            }
+        });
 
         // On the next iteration, we'll get details starting just after this end block.
         last_block_num = Some(end_block_num);
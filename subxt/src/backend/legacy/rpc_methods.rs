@@ -4,13 +4,25 @@
 
 //! An interface to call the raw legacy RPC methods.
 
+use crate::backend::legacy::subscribe_to_block_headers_filling_in_gaps;
 use crate::backend::rpc::{rpc_params, RpcClient, RpcSubscription};
+use crate::backend::utils::SharedStream;
+use crate::backend::{StreamOf, StreamOfResults};
+use crate::config::Header;
+use crate::dynamic::DecodedValue;
+use crate::error::{BlockError, MetadataError};
 use crate::metadata::Metadata;
+use crate::utils::SUBSTRATE_SS58_PREFIX;
 use crate::{Config, Error};
-use codec::Decode;
+use codec::{Decode, Encode};
 use derive_where::derive_where;
+use futures::{stream, Stream, StreamExt, TryStreamExt};
+use polkadot_sdk::sp_crypto_hashing;
 use primitive_types::U256;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub use crate::utils::{DispatchClass, Weight};
 
 /// An interface to call the legacy RPC methods. This interface is instantiated with
 /// some `T: Config` trait which determines some of the types that the RPC methods will
@@ -41,6 +53,50 @@ impl<T: Config> LegacyRpcMethods<T> {
         Ok(data.map(|b| b.0))
     }
 
+    /// Fetch the value at a given storage key and SCALE decode it into `V`. Returns `None`
+    /// if there is no value at the key, and an error naming the key if a value exists but
+    /// cannot be decoded into `V`.
+    pub async fn state_get_storage_decoded<V: Decode>(
+        &self,
+        key: &[u8],
+        hash: Option<T::Hash>,
+    ) -> Result<Option<V>, Error> {
+        let Some(data) = self.state_get_storage(key, hash).await? else {
+            return Ok(None);
+        };
+        let value = V::decode(&mut &*data).map_err(|e| {
+            Error::Other(format!(
+                "Cannot decode storage value at key {}: {e}",
+                to_hex(key)
+            ))
+        })?;
+        Ok(Some(value))
+    }
+
+    /// Fetch the value at a given storage key and SCALE decode it into `V`, decoding the
+    /// `default` bytes instead if there is no value at the key. Storage entries which have a
+    /// default value (such as counters, which default to 0) expose their default bytes in
+    /// the metadata via [`crate::metadata::types::StorageEntryMetadata::default_bytes()`].
+    ///
+    /// Prefer [`crate::storage::Storage::fetch_or_default()`] when working with storage
+    /// addresses, which looks up the default value in the metadata for you.
+    pub async fn state_get_storage_or_default<V: Decode>(
+        &self,
+        key: &[u8],
+        default: &[u8],
+        hash: Option<T::Hash>,
+    ) -> Result<V, Error> {
+        if let Some(value) = self.state_get_storage_decoded(key, hash).await? {
+            return Ok(value);
+        }
+        V::decode(&mut &*default).map_err(|e| {
+            Error::Other(format!(
+                "Cannot decode default storage value for key {}: {e}",
+                to_hex(key)
+            ))
+        })
+    }
+
     /// Returns the keys with prefix with pagination support.
     /// Up to `count` keys will be returned.
     /// If `start_key` is passed, return next keys in storage in lexicographic order.
@@ -57,6 +113,75 @@ impl<T: Config> LegacyRpcMethods<T> {
         Ok(data.into_iter().map(|b| b.0).collect())
     }
 
+    /// Returns a stream of all of the keys with the given prefix. Pages of up to `page_size`
+    /// keys are lazily fetched using `state_getKeysPaged` as the stream is polled, and the
+    /// stream ends once a page containing fewer than `page_size` keys is returned.
+    ///
+    /// The key that each page starts from is never handed back twice, even if the node
+    /// includes it again at the start of the next page.
+    pub fn state_get_keys_stream(
+        &self,
+        prefix: &[u8],
+        page_size: u32,
+        at: Option<T::Hash>,
+    ) -> impl Stream<Item = Result<StorageKey, Error>> + Send + 'static {
+        let methods = self.clone();
+        let prefix = prefix.to_vec();
+
+        let pages =
+            stream::try_unfold((None::<StorageKey>, false), move |(start_key, finished)| {
+                let methods = methods.clone();
+                let prefix = prefix.clone();
+                async move {
+                    if finished {
+                        return Ok::<_, Error>(None);
+                    }
+
+                    let keys = methods
+                        .state_get_keys_paged(&prefix, page_size, start_key.as_deref(), at)
+                        .await?;
+
+                    // A short page means that there is nothing left to fetch. Also stop if we
+                    // haven't made any progress, to avoid asking for the same page forever.
+                    let next_start_key = keys.last().cloned();
+                    let finished = keys.len() < page_size as usize
+                        || next_start_key.is_none()
+                        || next_start_key == start_key;
+
+                    let keys: Vec<StorageKey> = keys
+                        .into_iter()
+                        .filter(|key| Some(key) != start_key.as_ref())
+                        .collect();
+
+                    Ok(Some((keys, (next_start_key, finished))))
+                }
+            });
+
+        pages
+            .map_ok(|keys| stream::iter(keys.into_iter().map(Ok)))
+            .try_flatten()
+    }
+
+    /// Count the keys with the given prefix, for instance to find the number of entries in a
+    /// storage map, at some block (using the best block if none is given). All of the keys are
+    /// paged through (but not handed back) via [`Self::state_get_keys_stream`], so this takes
+    /// time proportional to the number of keys. Prefer reading a counter from storage instead
+    /// if one is available, such as the `CounterFor*` entries that counted maps come with.
+    pub async fn state_count_keys(&self, prefix: &[u8], at: Option<T::Hash>) -> Result<u64, Error> {
+        // Count every page at the same block, even if new blocks arrive while we do so.
+        let at = match at {
+            Some(at) => at,
+            None => self
+                .chain_get_block_hash(None)
+                .await?
+                .ok_or_else(|| Error::Other("No best block hash found".to_string()))?,
+        };
+
+        self.state_get_keys_stream(prefix, 1000, Some(at))
+            .try_fold(0, |count, _| futures::future::ready(Ok(count + 1)))
+            .await
+    }
+
     /// Query historical storage entries in the range from the start block to the end block,
     /// defaulting the end block to the current best block if it's not given. The first
     /// [`StorageChangeSet`] returned has all of the values for each key, and subsequent ones
@@ -91,6 +216,74 @@ impl<T: Config> LegacyRpcMethods<T> {
             .map_err(Into::into)
     }
 
+    /// Fetch the raw bytes for a batch of storage keys at some block, using the best block
+    /// if none is given. This makes a single `state_queryStorageAt` call, and the values
+    /// returned are in the same order as the keys that were provided, with `None` for any
+    /// key that has no value.
+    pub async fn storage_batch(
+        &self,
+        keys: impl IntoIterator<Item = &[u8]>,
+        at: Option<T::Hash>,
+    ) -> Result<Vec<Option<StorageData>>, Error> {
+        let keys: Vec<&[u8]> = keys.into_iter().collect();
+        let change_sets = self
+            .state_query_storage_at(keys.iter().copied(), at)
+            .await?;
+
+        // The node is free to hand back changes in any order (and may omit keys
+        // with no value entirely), so index them by key before lining them up.
+        let values: HashMap<Vec<u8>, Option<StorageData>> = change_sets
+            .into_iter()
+            .flat_map(|change_set| change_set.changes)
+            .map(|(key, value)| (key.0, value.map(|v| v.0)))
+            .collect();
+
+        Ok(keys
+            .into_iter()
+            .map(|key| values.get(key).cloned().flatten())
+            .collect())
+    }
+
+    /// Fetch the raw bytes for a given key in a child trie, using the best block if no
+    /// block hash is given.
+    ///
+    /// `child_key` is the prefixed storage key of the child trie, ie
+    /// `b":child_storage:default:"` followed by the child trie's unique ID. Pallets derive
+    /// this ID themselves; crowdloans, for example, use the blake2_256 hash of `b"crowdloan"`
+    /// followed by the SCALE encoded fund index, and contributions are then keyed by the
+    /// SCALE encoded contributor account ID within that child trie.
+    pub async fn childstate_get_storage(
+        &self,
+        child_key: &[u8],
+        key: &[u8],
+        hash: Option<T::Hash>,
+    ) -> Result<Option<StorageData>, Error> {
+        let params = rpc_params![to_hex(child_key), to_hex(key), hash];
+        let data: Option<Bytes> = self.client.request("childstate_getStorage", params).await?;
+        Ok(data.map(|b| b.0))
+    }
+
+    /// Returns the keys with prefix in a child trie with pagination support. See
+    /// [`Self::childstate_get_storage`] for what `child_key` is expected to be.
+    /// Up to `count` keys will be returned.
+    /// If `start_key` is passed, return next keys in storage in lexicographic order.
+    pub async fn childstate_get_keys_paged(
+        &self,
+        child_key: &[u8],
+        prefix: &[u8],
+        count: u32,
+        start_key: Option<&[u8]>,
+        at: Option<T::Hash>,
+    ) -> Result<Vec<StorageKey>, Error> {
+        let start_key = start_key.map(to_hex);
+        let params = rpc_params![to_hex(child_key), to_hex(prefix), count, start_key, at];
+        let data: Vec<Bytes> = self
+            .client
+            .request("childstate_getKeysPaged", params)
+            .await?;
+        Ok(data.into_iter().map(|b| b.0).collect())
+    }
+
     /// Fetch the genesis hash
     pub async fn genesis_hash(&self) -> Result<T::Hash, Error> {
         let block_zero = 0u32;
@@ -101,20 +294,58 @@ impl<T: Config> LegacyRpcMethods<T> {
     }
 
     /// Fetch the metadata via the legacy `state_getMetadata` RPC method.
+    ///
+    /// If the node hands back a version of metadata that Subxt doesn't support (ie anything
+    /// prior to V14), this returns an [`Error::MetadataDecoding`] error containing
+    /// [`crate::error::MetadataTryFromError::UnsupportedMetadataVersion`].
     pub async fn state_get_metadata(&self, at: Option<T::Hash>) -> Result<Metadata, Error> {
         let bytes: Bytes = self
             .client
             .request("state_getMetadata", rpc_params![at])
             .await?;
-        let metadata = Metadata::decode(&mut &bytes[..])?;
+        let metadata = frame_metadata::RuntimeMetadataPrefixed::decode(&mut &bytes[..])?;
+        let metadata = Metadata::try_from(metadata)?;
         Ok(metadata)
     }
 
+    /// Fetch the version of the metadata returned by the legacy `state_getMetadata` RPC
+    /// method, without decoding the rest of it. This is useful for checking whether a node
+    /// is compatible with Subxt, which requires V14 metadata or later.
+    pub async fn state_get_metadata_version(&self, at: Option<T::Hash>) -> Result<u32, Error> {
+        let bytes: Bytes = self
+            .client
+            .request("state_getMetadata", rpc_params![at])
+            .await?;
+
+        // The metadata starts with a 4 byte magic number, followed by the index of the
+        // `RuntimeMetadata` enum variant, which is also the metadata version.
+        let input = &mut &bytes[..];
+        let magic = u32::decode(input)?;
+        if magic != frame_metadata::META_RESERVED {
+            return Err(Error::Other(format!(
+                "Metadata has an unexpected magic number: {magic:#x}"
+            )));
+        }
+        let version = u8::decode(input)?;
+        Ok(version as u32)
+    }
+
     /// Fetch system health
     pub async fn system_health(&self) -> Result<SystemHealth, Error> {
         self.client.request("system_health", rpc_params![]).await
     }
 
+    /// Fetch the sync state of the node
+    pub async fn system_sync_state(&self) -> Result<SystemSyncState, Error> {
+        self.client.request("system_syncState", rpc_params![]).await
+    }
+
+    /// Fetch the currently connected peers of the node. This is an unsafe RPC method,
+    /// and so is typically only available on nodes which have been configured to allow it.
+    pub async fn system_peers(&self) -> Result<Vec<SystemPeerInfo<T::Hash>>, Error> {
+        self.client.request("system_peers", rpc_params![]).await
+    }
+
     /// Fetch system chain
     pub async fn system_chain(&self) -> Result<String, Error> {
         self.client.request("system_chain", rpc_params![]).await
@@ -137,6 +368,27 @@ impl<T: Config> LegacyRpcMethods<T> {
             .await
     }
 
+    /// Fetch the SS58 address prefix that the chain uses, from the `ss58Format` field of its
+    /// system properties. Some chains don't provide this field, in which case the generic
+    /// Substrate prefix of 42 is returned, since that is what those chains use.
+    ///
+    /// This can be handed to [`crate::utils::AccountId32::to_ss58check_with_version`] to
+    /// display accounts in the chain's address format.
+    pub async fn system_ss58_prefix(&self) -> Result<u16, Error> {
+        let properties = self.system_properties().await?;
+        let Some(ss58_format) = properties.get("ss58Format") else {
+            return Ok(SUBSTRATE_SS58_PREFIX);
+        };
+        ss58_format
+            .as_u64()
+            .and_then(|prefix| u16::try_from(prefix).ok())
+            .ok_or_else(|| {
+                Error::Other(format!(
+                    "Invalid ss58Format in system properties: {ss58_format}"
+                ))
+            })
+    }
+
     /// Fetch next nonce for an Account
     ///
     /// Return account nonce adjusted for extrinsics currently in transaction pool
@@ -169,6 +421,36 @@ impl<T: Config> LegacyRpcMethods<T> {
         Ok(block_hash)
     }
 
+    /// Get the headers for every block number in the inclusive range `from..=to`, in order.
+    ///
+    /// Each block number is resolved to a hash and then the header is fetched, with up to 16
+    /// blocks being fetched concurrently. An error is returned naming the block number if
+    /// any block in the range has no hash or header (for instance, because it's been pruned
+    /// or doesn't exist yet).
+    pub async fn chain_get_headers_in_range(
+        &self,
+        from: u64,
+        to: u64,
+    ) -> Result<Vec<T::Header>, Error> {
+        const MAX_CONCURRENT_REQUESTS: usize = 16;
+
+        stream::iter(from..=to)
+            .map(|number| async move {
+                let hash = self
+                    .chain_get_block_hash(Some(number.into()))
+                    .await?
+                    .ok_or_else(|| {
+                        Error::Other(format!("No block hash found for block #{number}"))
+                    })?;
+                self.chain_get_header(Some(hash))
+                    .await?
+                    .ok_or_else(|| Error::Other(format!("No header found for block #{number}")))
+            })
+            .buffered(MAX_CONCURRENT_REQUESTS)
+            .try_collect()
+            .await
+    }
+
     /// Get a block hash of the latest finalized block
     pub async fn chain_get_finalized_head(&self) -> Result<T::Hash, Error> {
         let hash = self
@@ -178,6 +460,19 @@ impl<T: Config> LegacyRpcMethods<T> {
         Ok(hash)
     }
 
+    /// Get the block number of the latest finalized block. An error naming the finalized
+    /// block hash is returned if the header for it can't be found.
+    pub async fn chain_get_finalized_block_number(
+        &self,
+    ) -> Result<<T::Header as Header>::Number, Error> {
+        let hash = self.chain_get_finalized_head().await?;
+        let header = self
+            .chain_get_header(Some(hash))
+            .await?
+            .ok_or_else(|| BlockError::not_found(hash))?;
+        Ok(header.number())
+    }
+
     /// Get a Block
     pub async fn chain_get_block(
         &self,
@@ -188,6 +483,31 @@ impl<T: Config> LegacyRpcMethods<T> {
         Ok(block)
     }
 
+    /// Get the block with the given number. Returns `None` if there is no block hash for
+    /// this number (for instance, because the block doesn't exist yet).
+    pub async fn chain_get_block_by_number(
+        &self,
+        block_number: impl Into<BlockNumber>,
+    ) -> Result<Option<BlockDetails<T>>, Error> {
+        let Some(hash) = self.chain_get_block_hash(Some(block_number.into())).await? else {
+            return Ok(None);
+        };
+        self.chain_get_block(Some(hash)).await
+    }
+
+    /// Return the hash of the block identified by the given [`BlockId`]. Block numbers are
+    /// resolved via `chain_getBlockHash`, returning an error if there is no block with that
+    /// number, while block hashes are handed back as is.
+    pub async fn chain_resolve_block(&self, block: BlockId<T::Hash>) -> Result<T::Hash, Error> {
+        match block {
+            BlockId::Hash(hash) => Ok(hash),
+            BlockId::Number(number) => self
+                .chain_get_block_hash(Some(number.into()))
+                .await?
+                .ok_or_else(|| Error::Other(format!("No block hash found for block #{number}"))),
+        }
+    }
+
     /// Reexecute the specified `block_hash` and gather statistics while doing so.
     ///
     /// This function requires the specified block and its parent to be available
@@ -281,6 +601,98 @@ impl<T: Config> LegacyRpcMethods<T> {
         Ok(subscription)
     }
 
+    /// Subscribe to finalized block headers via a single `chain_subscribeFinalizedHeads`
+    /// subscription which can be shared between any number of consumers. Clone the returned
+    /// [`SharedStream`] to hand a new consumer the headers finalized from then on; the
+    /// subscription stays open until every clone has been dropped.
+    ///
+    /// Note: as with [`Self::chain_subscribe_finalized_heads`], this may not produce
+    /// _every_ block in the finalized chain.
+    pub async fn chain_subscribe_finalized_heads_shared(
+        &self,
+    ) -> Result<SharedStream<T::Header>, Error>
+    where
+        T::Header: Clone + Send,
+    {
+        let subscription = self.chain_subscribe_finalized_heads().await?;
+        Ok(SharedStream::new(StreamOf::new(Box::pin(subscription))))
+    }
+
+    /// Subscribe to finalized block headers, starting from the block with the given hash.
+    /// The headers of the given block and every block after it up to the current finalized
+    /// block are handed back first, followed by every newly finalized block as it arrives.
+    ///
+    /// Each block is handed back once; if the subscription reports a block at or below the
+    /// last one handed back (for instance at the handoff from fetching past blocks to
+    /// following new ones), it is skipped. This makes it possible to resume processing
+    /// (for instance, the events in each block) from the last block that was processed.
+    ///
+    /// An error is returned if the given block is not in the canonical chain.
+    pub async fn chain_subscribe_finalized_heads_from(
+        &self,
+        from: T::Hash,
+    ) -> Result<StreamOfResults<T::Header>, Error> {
+        let from_block_num: u64 = self
+            .chain_get_header(Some(from))
+            .await?
+            .ok_or_else(|| BlockError::not_found(from))?
+            .number()
+            .into();
+        if self
+            .chain_get_block_hash(Some(from_block_num.into()))
+            .await?
+            != Some(from)
+        {
+            return Err(Error::Other(format!(
+                "Block {from:?} is not in the canonical chain"
+            )));
+        }
+
+        let subscription = self.chain_subscribe_finalized_heads().await?;
+        let headers = subscribe_to_block_headers_filling_in_gaps(
+            self.clone(),
+            subscription,
+            from_block_num.checked_sub(1),
+        );
+
+        let mut last_block_num = from_block_num.checked_sub(1);
+        let headers = headers.try_filter(move |header| {
+            let block_num = header.number().into();
+            let is_new = last_block_num.map_or(true, |last| block_num > last);
+            if is_new {
+                last_block_num = Some(block_num);
+            }
+            futures::future::ready(is_new)
+        });
+        Ok(StreamOf::new(Box::pin(headers)))
+    }
+
+    /// Subscribe to finalized blocks, handing back the full details of each block rather than
+    /// just the header. Unlike [`Self::chain_subscribe_finalized_heads`], every block in the
+    /// finalized chain is handed back, filling in any blocks that the node skips over.
+    ///
+    /// Each block is only fetched once the previous one has been handed back and the stream
+    /// is polled again, so fetching never runs ahead of the consumer.
+    pub async fn chain_subscribe_finalized_blocks(
+        &self,
+    ) -> Result<StreamOfResults<BlockDetails<T>>, Error> {
+        let subscription = self.chain_subscribe_finalized_heads().await?;
+        let headers = subscribe_to_block_headers_filling_in_gaps(self.clone(), subscription, None);
+
+        let methods = self.clone();
+        let blocks = headers.and_then(move |header| {
+            let methods = methods.clone();
+            async move {
+                let block_num: u64 = header.number().into();
+                methods
+                    .chain_get_block(Some(header.hash()))
+                    .await?
+                    .ok_or_else(|| Error::Other(format!("No block found for block #{block_num}")))
+            }
+        });
+        Ok(StreamOf::new(Box::pin(blocks)))
+    }
+
     /// Subscribe to runtime version updates that produce changes in the metadata.
     /// The first item emitted by the stream is the current runtime version.
     pub async fn state_subscribe_runtime_version(
@@ -297,6 +709,30 @@ impl<T: Config> LegacyRpcMethods<T> {
         Ok(subscription)
     }
 
+    /// Subscribe to changes in the values stored at the given storage keys. The first
+    /// [`StorageChangeSet`] emitted contains the current values of the keys, and subsequent
+    /// ones contain the keys whose values changed in each new block.
+    ///
+    /// If no keys are given, this subscribes to changes in _all_ storage entries.
+    pub async fn state_subscribe_storage(
+        &self,
+        keys: impl IntoIterator<Item = &[u8]>,
+    ) -> Result<RpcSubscription<StorageChangeSet<T::Hash>>, Error> {
+        let keys: Vec<String> = keys.into_iter().map(to_hex).collect();
+        // The node only subscribes to all storage changes if no keys are
+        // given at all; an empty list of keys would never produce anything.
+        let params = if keys.is_empty() {
+            rpc_params![]
+        } else {
+            rpc_params![keys]
+        };
+        let subscription = self
+            .client
+            .subscribe("state_subscribeStorage", params, "state_unsubscribeStorage")
+            .await?;
+        Ok(subscription)
+    }
+
     /// Create and submit an extrinsic and return corresponding Hash if successful
     pub async fn author_submit_extrinsic(&self, extrinsic: &[u8]) -> Result<T::Hash, Error> {
         let params = rpc_params![to_hex(extrinsic)];
@@ -384,6 +820,91 @@ impl<T: Config> LegacyRpcMethods<T> {
         Ok(bytes.0)
     }
 
+    /// Execute a runtime API call via `state_call` and SCALE decode the result into `R`.
+    /// `call_parameters` should be the SCALE encoded arguments to the runtime API function,
+    /// and an error naming the function is returned if the result cannot be decoded into `R`.
+    pub async fn state_call_decoded<R: Decode>(
+        &self,
+        function: &str,
+        call_parameters: Option<&[u8]>,
+        at: Option<T::Hash>,
+    ) -> Result<R, Error> {
+        let bytes = self.state_call(function, call_parameters, at).await?;
+        R::decode(&mut &*bytes).map_err(|e| {
+            Error::Other(format!(
+                "Cannot decode the result of runtime API call {function}: {e}"
+            ))
+        })
+    }
+
+    /// Fetch the weight, dispatch class and partial fee of the given SCALE encoded extrinsic
+    /// via the `TransactionPaymentApi_query_info` runtime API.
+    ///
+    /// Runtimes exposing version 1 of `TransactionPaymentApi` return the weight as a single
+    /// `u64`, and newer ones return a two dimensional weight. The version is looked up in the
+    /// runtime version at the block the call is made against, and the result is decoded
+    /// accordingly. If no block hash is given, the current best block is used for both.
+    pub async fn query_fee_info(
+        &self,
+        encoded_xt: &[u8],
+        at: Option<T::Hash>,
+    ) -> Result<RuntimeDispatchInfo, Error> {
+        let at = match at {
+            Some(at) => at,
+            None => self
+                .chain_get_block_hash(None)
+                .await?
+                .ok_or_else(|| Error::Other("Best block hash not found".into()))?,
+        };
+
+        let api_version = self
+            .state_get_runtime_version(Some(at))
+            .await?
+            .api_version("TransactionPaymentApi")
+            .ok_or_else(|| {
+                Error::Other("The runtime does not expose TransactionPaymentApi".into())
+            })?;
+
+        let mut params = encoded_xt.to_vec();
+        (encoded_xt.len() as u32).encode_to(&mut params);
+
+        let function = "TransactionPaymentApi_query_info";
+        if api_version < 2 {
+            let (ref_time, class, partial_fee): (u64, DispatchClass, u128) = self
+                .state_call_decoded(function, Some(&params), Some(at))
+                .await?;
+            Ok(RuntimeDispatchInfo {
+                weight: Weight {
+                    ref_time,
+                    proof_size: 0,
+                },
+                class,
+                partial_fee,
+            })
+        } else {
+            self.state_call_decoded(function, Some(&params), Some(at))
+                .await
+        }
+    }
+
+    /// Fetch a breakdown of the fees that would be charged for the given SCALE encoded
+    /// extrinsic via the `TransactionPaymentApi_query_fee_details` runtime API, using the
+    /// current best block if no block hash is given.
+    ///
+    /// The [`FeeDetails::inclusion_fee`] is `None` for extrinsics which are not charged an
+    /// inclusion fee, such as unsigned extrinsics or calls which are declared to be free.
+    pub async fn query_fee_details(
+        &self,
+        encoded_xt: &[u8],
+        at: Option<T::Hash>,
+    ) -> Result<FeeDetails, Error> {
+        let mut params = encoded_xt.to_vec();
+        (encoded_xt.len() as u32).encode_to(&mut params);
+
+        self.state_call_decoded("TransactionPaymentApi_query_fee_details", Some(&params), at)
+            .await
+    }
+
     /// Submits the extrinsic to the dry_run RPC, to test if it would succeed.
     ///
     /// Returns a [`DryRunResult`], which is the result of performing the dry run.
@@ -396,6 +917,58 @@ impl<T: Config> LegacyRpcMethods<T> {
         let result_bytes: Bytes = self.client.request("system_dryRun", params).await?;
         Ok(DryRunResultBytes(result_bytes.0))
     }
+
+    /// Submits each of the given extrinsics to the dry_run RPC concurrently, returning the
+    /// results in the same order as the extrinsics were given.
+    ///
+    /// Every dry run is performed against the same block. If no block hash is given, the
+    /// current best block hash is looked up once up front and used for all of them, so that
+    /// the results are consistent even if a new block is produced in the meantime.
+    pub async fn dry_run_batch(
+        &self,
+        encoded_signed: impl IntoIterator<Item = &[u8]>,
+        at: Option<T::Hash>,
+    ) -> Result<Vec<DryRunResultBytes>, Error> {
+        let at = match at {
+            Some(at) => at,
+            None => self
+                .chain_get_block_hash(None)
+                .await?
+                .ok_or_else(|| Error::Other("Best block hash not found".into()))?,
+        };
+
+        let dry_runs = encoded_signed
+            .into_iter()
+            .map(|encoded_signed| self.dry_run(encoded_signed, Some(at)));
+        futures::future::try_join_all(dry_runs).await
+    }
+
+    /// Submits the extrinsic to the dry_run RPC, and decodes the result using the provided
+    /// [`Metadata`].
+    ///
+    /// Unlike [`DryRunResultBytes::into_dry_run_result`], if the dry run fails with a module
+    /// error, the pallet and error indexes are also checked against the metadata, and a
+    /// [`crate::error::MetadataError`] is returned if they cannot be found. This means that
+    /// calling [`crate::error::ModuleError::details()`] on any returned module error will succeed.
+    pub async fn dry_run_decoded(
+        &self,
+        encoded_signed: &[u8],
+        at: Option<T::Hash>,
+        metadata: &Metadata,
+    ) -> Result<DryRunResult, Error> {
+        let result = self
+            .dry_run(encoded_signed, at)
+            .await?
+            .into_dry_run_result(metadata)?;
+
+        if let DryRunResult::DispatchError(crate::error::DispatchError::Module(module_error)) =
+            &result
+        {
+            module_error.details()?;
+        }
+
+        Ok(result)
+    }
 }
 
 /// Storage key.
@@ -418,6 +991,32 @@ pub struct SystemHealth {
     pub should_have_peers: bool,
 }
 
+/// Sync state struct returned by the RPC
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemSyncState {
+    /// Height of the block at which syncing started.
+    pub starting_block: u64,
+    /// Height of the current best block of the node.
+    pub current_block: u64,
+    /// Height of the highest block in the network.
+    pub highest_block: u64,
+}
+
+/// Information about a connected peer, as returned by the RPC
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemPeerInfo<Hash> {
+    /// Peer ID
+    pub peer_id: String,
+    /// Roles
+    pub roles: String,
+    /// Peer best block hash
+    pub best_hash: Hash,
+    /// Peer best block number
+    pub best_number: u64,
+}
+
 /// System properties; an arbitrary JSON object.
 pub type SystemProperties = serde_json::Map<String, serde_json::Value>;
 
@@ -476,6 +1075,79 @@ pub struct RuntimeVersion {
     pub other: std::collections::HashMap<String, serde_json::Value>,
 }
 
+impl RuntimeVersion {
+    /// Return the version of the runtime API with the given name (for example
+    /// `"TransactionPaymentApi"`), or `None` if the runtime does not expose it.
+    pub fn api_version(&self, api_name: &str) -> Option<u32> {
+        let api_id = to_hex(sp_crypto_hashing::blake2_64(api_name.as_bytes()));
+        let version = match self.other.get("apis")? {
+            // The legacy RPCs hand back a list of `[id, version]` pairs.
+            serde_json::Value::Array(apis) => apis.iter().find_map(|api| {
+                let [id, version] = api.as_array()?.as_slice() else {
+                    return None;
+                };
+                (id.as_str()? == api_id).then_some(version)
+            })?,
+            serde_json::Value::Object(apis) => apis.get(&api_id)?,
+            _ => return None,
+        };
+        version.as_u64()?.try_into().ok()
+    }
+}
+
+/// The weight, dispatch class and partial fee of an extrinsic, as returned from
+/// [`LegacyRpcMethods::query_fee_info`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Decode)]
+pub struct RuntimeDispatchInfo {
+    /// The weight of the extrinsic.
+    pub weight: Weight,
+    /// The class of the extrinsic.
+    pub class: DispatchClass,
+    /// The inclusion fee of the extrinsic, not including any tip.
+    pub partial_fee: u128,
+}
+
+/// A breakdown of the fees charged for an extrinsic, as returned from
+/// [`LegacyRpcMethods::query_fee_details`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Decode)]
+pub struct FeeDetails {
+    /// The fees charged for including the extrinsic in a block, or `None` if the
+    /// extrinsic is not charged an inclusion fee.
+    pub inclusion_fee: Option<InclusionFee>,
+    /// The tip paid for the extrinsic.
+    pub tip: u128,
+}
+
+impl FeeDetails {
+    /// The total fee charged for the extrinsic, including the tip.
+    pub fn final_fee(&self) -> u128 {
+        self.inclusion_fee
+            .map(|fee| fee.inclusion_fee())
+            .unwrap_or(0)
+            .saturating_add(self.tip)
+    }
+}
+
+/// The fees charged for including an extrinsic in a block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Decode)]
+pub struct InclusionFee {
+    /// The minimum fee charged for any extrinsic.
+    pub base_fee: u128,
+    /// The fee charged based on the length of the extrinsic in bytes.
+    pub len_fee: u128,
+    /// The fee charged based on the weight of the extrinsic, adjusted by the fee multiplier.
+    pub adjusted_weight_fee: u128,
+}
+
+impl InclusionFee {
+    /// The sum of the base, length and adjusted weight fees.
+    pub fn inclusion_fee(&self) -> u128 {
+        self.base_fee
+            .saturating_add(self.len_fee)
+            .saturating_add(self.adjusted_weight_fee)
+    }
+}
+
 /// Possible transaction status events.
 ///
 /// # Note
@@ -568,6 +1240,85 @@ pub struct StorageChangeSet<Hash> {
     pub changes: Vec<(Bytes, Option<Bytes>)>,
 }
 
+impl<Hash: Clone> StorageChangeSet<Hash> {
+    /// Decode each of the changes in this change set, using the given metadata to work out
+    /// which storage entry each key belongs to and how to decode its value. This hands back
+    /// the hash of the block that the changes were made in, and the result of decoding each
+    /// change in order. Decoding a change fails if its key does not belong to any storage
+    /// entry in the metadata (naming the key), or if its value cannot be decoded into the
+    /// type that its storage entry expects; this does not stop the other changes decoding.
+    pub fn decode_changes(
+        &self,
+        metadata: &Metadata,
+    ) -> (Hash, Vec<Result<DecodedStorageChange, Error>>) {
+        let decoded_changes = self
+            .changes
+            .iter()
+            .map(|(key, value)| decode_storage_change(metadata, key, value.as_ref()))
+            .collect();
+        (self.block.clone(), decoded_changes)
+    }
+}
+
+fn decode_storage_change(
+    metadata: &Metadata,
+    key: &Bytes,
+    value: Option<&Bytes>,
+) -> Result<DecodedStorageChange, Error> {
+    let Some((pallet_name, entry_name)) = storage_entry_for_key(metadata, key) else {
+        let key = to_hex(&key.0);
+        return Err(MetadataError::StorageEntryNotFound(key).into());
+    };
+
+    let value = match value {
+        Some(value) => {
+            let address = subxt_core::storage::address::dynamic(pallet_name, entry_name, ());
+            let value = subxt_core::storage::decode_value(&mut &**value, &address, metadata)?;
+            Some(value.to_value()?)
+        }
+        None => None,
+    };
+
+    Ok(DecodedStorageChange {
+        pallet_name: pallet_name.to_owned(),
+        entry_name: entry_name.to_owned(),
+        key: key.0.clone(),
+        value,
+    })
+}
+
+/// A change to some storage entry, as decoded by [`StorageChangeSet::decode_changes`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedStorageChange {
+    /// The name of the pallet that the storage entry belongs to.
+    pub pallet_name: String,
+    /// The name of the storage entry.
+    pub entry_name: String,
+    /// The full storage key that changed.
+    pub key: Vec<u8>,
+    /// The new value at this key, or `None` if the value was removed.
+    pub value: Option<DecodedValue>,
+}
+
+// Storage keys begin with twox128(pallet prefix) ++ twox128(entry name), so use these to
+// find the names of the pallet and storage entry that some key belongs to.
+fn storage_entry_for_key<'a>(metadata: &'a Metadata, key: &[u8]) -> Option<(&'a str, &'a str)> {
+    let (pallet_hash, rest) = key.split_at_checked(16)?;
+    let entry_hash = rest.get(..16)?;
+
+    metadata.pallets().find_map(|pallet| {
+        let storage = pallet.storage()?;
+        if sp_crypto_hashing::twox_128(storage.prefix().as_bytes()) != pallet_hash {
+            return None;
+        }
+        storage
+            .entries()
+            .iter()
+            .find(|entry| sp_crypto_hashing::twox_128(entry.name().as_bytes()) == entry_hash)
+            .map(|entry| (pallet.name(), entry.name()))
+    })
+}
+
 /// Statistics of a block returned by the `dev_getBlockStats` RPC.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -661,6 +1412,128 @@ impl From<U256> for NumberOrHex {
     }
 }
 
+// Like the unsigned types, non-negative numbers which fit into a u64 use the plain number
+// representation. Negative numbers are represented as 256 bit two's complement values, and
+// so always end up in the hex representation.
+impl From<i64> for NumberOrHex {
+    fn from(n: i64) -> Self {
+        NumberOrHex::from(i128::from(n))
+    }
+}
+
+impl From<i128> for NumberOrHex {
+    fn from(n: i128) -> Self {
+        let magnitude = U256::from(n.unsigned_abs());
+        if n < 0 {
+            NumberOrHex::Hex(U256::zero().overflowing_sub(magnitude).0)
+        } else if let Ok(n) = u64::try_from(n) {
+            NumberOrHex::Number(n)
+        } else {
+            NumberOrHex::Hex(magnitude)
+        }
+    }
+}
+
+/// An error converting a [`NumberOrHex`] into some primitive integer type which is too small
+/// to hold it. This contains the value that didn't fit, and the name of the target type.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("{value} does not fit into a {target}")]
+pub struct TryFromIntError {
+    /// The value that could not be converted.
+    pub value: U256,
+    /// The name of the type that we tried to convert into.
+    pub target: &'static str,
+}
+
+macro_rules! try_from_number_or_hex {
+    ($($t: ty)+) => {
+        $(
+            impl TryFrom<NumberOrHex> for $t {
+                type Error = TryFromIntError;
+                fn try_from(num_or_hex: NumberOrHex) -> Result<$t, TryFromIntError> {
+                    let value = num_or_hex.into_u256();
+                    <$t>::try_from(value).map_err(|_| TryFromIntError {
+                        value,
+                        target: stringify!($t),
+                    })
+                }
+            }
+        )+
+    }
+}
+try_from_number_or_hex!(u8 u16 u32 u64 u128);
+
+macro_rules! try_from_number_or_hex_signed {
+    ($($t: ty)+) => {
+        $(
+            impl TryFrom<NumberOrHex> for $t {
+                type Error = TryFromIntError;
+                fn try_from(num_or_hex: NumberOrHex) -> Result<$t, TryFromIntError> {
+                    let value = num_or_hex.into_u256();
+                    let err = || TryFromIntError {
+                        value,
+                        target: stringify!($t),
+                    };
+
+                    // Interpret the value as a 256 bit two's complement number:
+                    let is_negative = value.bit(255);
+                    let magnitude = if is_negative {
+                        (!value).overflowing_add(U256::one()).0
+                    } else {
+                        value
+                    };
+                    let magnitude = u128::try_from(magnitude).map_err(|_| err())?;
+                    let n = if is_negative {
+                        0i128.checked_sub_unsigned(magnitude)
+                    } else {
+                        i128::try_from(magnitude).ok()
+                    };
+
+                    n.and_then(|n| <$t>::try_from(n).ok()).ok_or_else(err)
+                }
+            }
+        )+
+    }
+}
+try_from_number_or_hex_signed!(i64 i128);
+
+/// Either the number or the hash of some block, for instance as provided by a user. Use
+/// [`LegacyRpcMethods::chain_resolve_block`] to obtain the hash of the block in either case.
+///
+/// This can be parsed from a string; strings starting with `0x` are parsed as block hashes,
+/// and anything else as a block number.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BlockId<Hash> {
+    /// The number of the block.
+    Number(u64),
+    /// The hash of the block.
+    Hash(Hash),
+}
+
+impl<Hash> From<u64> for BlockId<Hash> {
+    fn from(number: u64) -> Self {
+        BlockId::Number(number)
+    }
+}
+
+impl<Hash: std::str::FromStr> std::str::FromStr for BlockId<Hash> {
+    type Err = ParseBlockIdError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let block_id = if s.starts_with("0x") {
+            s.parse().ok().map(BlockId::Hash)
+        } else {
+            s.parse().ok().map(BlockId::Number)
+        };
+        block_id.ok_or_else(|| ParseBlockIdError(s.to_owned()))
+    }
+}
+
+/// An error parsing a [`BlockId`] from a string which is neither a block number nor a
+/// `0x` prefixed block hash. This contains the string that could not be parsed.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("'{0}' is neither a block number nor a 0x prefixed block hash")]
+pub struct ParseBlockIdError(pub String);
+
 /// A quick helper to encode some bytes to hex.
 fn to_hex(bytes: impl AsRef<[u8]>) -> String {
     format!("0x{}", hex::encode(bytes.as_ref()))
@@ -680,3 +1553,140 @@ impl From<Vec<u8>> for Bytes {
         Bytes(s)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn number_or_hex_try_from_primitives() {
+        assert_eq!(u32::try_from(NumberOrHex::Number(7)), Ok(7));
+        assert_eq!(
+            u128::try_from(NumberOrHex::Hex(U256::from(u128::MAX))),
+            Ok(u128::MAX)
+        );
+
+        let too_big = NumberOrHex::Number(u64::from(u32::MAX) + 1);
+        let err = u32::try_from(too_big).unwrap_err();
+        assert_eq!(
+            err,
+            TryFromIntError {
+                value: U256::from(u64::from(u32::MAX) + 1),
+                target: "u32",
+            }
+        );
+        assert_eq!(err.to_string(), "4294967296 does not fit into a u32");
+
+        let err = u64::try_from(NumberOrHex::Hex(U256::MAX)).unwrap_err();
+        assert_eq!(err.value, U256::MAX);
+        assert_eq!(err.target, "u64");
+    }
+
+    #[test]
+    fn number_or_hex_signed_round_trip() {
+        fn round_trip<N>(n: N) -> N
+        where
+            N: Into<NumberOrHex> + TryFrom<NumberOrHex, Error = TryFromIntError>,
+        {
+            // Go via JSON, since that's what we'll be sending and receiving:
+            let json = serde_json::to_string(&n.into()).unwrap();
+            let num_or_hex: NumberOrHex = serde_json::from_str(&json).unwrap();
+            N::try_from(num_or_hex).unwrap()
+        }
+
+        for n in [0, 1, -1, 12345, -12345, i64::MAX, i64::MIN] {
+            assert_eq!(round_trip(n), n);
+            assert_eq!(round_trip(n as i128), n as i128);
+        }
+        for n in [i128::MAX, i128::MIN] {
+            assert_eq!(round_trip(n), n);
+        }
+
+        // Non-negative values which fit into a u64 use the plain number representation:
+        assert_eq!(NumberOrHex::from(5i64), NumberOrHex::Number(5));
+        assert_eq!(NumberOrHex::from(5i128), NumberOrHex::Number(5));
+        assert_eq!(
+            NumberOrHex::from(i128::from(u64::MAX)),
+            NumberOrHex::Number(u64::MAX)
+        );
+        assert_eq!(
+            NumberOrHex::from(i128::from(u64::MAX) + 1),
+            NumberOrHex::Hex(U256::from(u64::MAX) + 1)
+        );
+        assert_eq!(NumberOrHex::from(-1i64), NumberOrHex::Hex(U256::MAX));
+        assert_eq!(NumberOrHex::from(-1i128), NumberOrHex::Hex(U256::MAX));
+
+        // Values which don't fit are rejected:
+        assert!(i64::try_from(NumberOrHex::from(i128::MIN)).is_err());
+        assert!(i64::try_from(NumberOrHex::Number(u64::MAX)).is_err());
+        assert!(i128::try_from(NumberOrHex::Hex(U256::from(u128::MAX))).is_err());
+        assert!(i128::try_from(NumberOrHex::Hex(U256::MAX / 2)).is_err());
+    }
+
+    #[test]
+    fn storage_change_set_decode_changes() {
+        use codec::Decode;
+
+        let metadata_bytes = include_bytes!("../../../../artifacts/polkadot_metadata_small.scale");
+        let metadata = Metadata::decode(&mut &metadata_bytes[..]).unwrap();
+        let root_key = |pallet: &str, entry: &str| {
+            subxt_core::storage::address::dynamic(pallet, entry, ()).to_root_bytes()
+        };
+
+        // System.Number is a u32, and map keys have more bytes after the root key:
+        let number_key = root_key("System", "Number");
+        let mut account_key = root_key("System", "Account");
+        account_key.extend([1; 48]);
+
+        // Keys which don't belong to any storage entry fail to decode without stopping
+        // the other changes from decoding:
+        let change_set = StorageChangeSet {
+            block: 1u64,
+            changes: vec![
+                (Bytes(number_key.clone()), Some(Bytes(1234u32.encode()))),
+                (Bytes(vec![1; 32]), None),
+                (Bytes(account_key.clone()), None),
+            ],
+        };
+        let (block, changes) = change_set.decode_changes(&metadata);
+        assert_eq!(block, 1);
+        assert!(matches!(
+            changes[1],
+            Err(Error::Metadata(MetadataError::StorageEntryNotFound(_)))
+        ));
+        let changes: Vec<_> = [&changes[0], &changes[2]]
+            .into_iter()
+            .map(|c| {
+                let c = c.as_ref().unwrap();
+                let value = c.value.as_ref().map(|v| v.as_u128());
+                (c.pallet_name.as_str(), c.entry_name.as_str(), &c.key, value)
+            })
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                ("System", "Number", &number_key, Some(Some(1234))),
+                ("System", "Account", &account_key, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn block_id_from_str() {
+        use primitive_types::H256;
+
+        let hash = H256::from_low_u64_be(1);
+        assert_eq!(
+            format!("{hash:?}").parse::<BlockId<H256>>(),
+            Ok(BlockId::Hash(hash))
+        );
+        assert_eq!("1234".parse::<BlockId<H256>>(), Ok(BlockId::Number(1234)));
+
+        for invalid in ["", "0x12", "-1", "1234a", "12345678901234567890123"] {
+            assert_eq!(
+                invalid.parse::<BlockId<H256>>(),
+                Err(ParseBlockIdError(invalid.to_owned()))
+            );
+        }
+    }
+}
@@ -331,7 +331,7 @@ pub struct StorageResponse {
 }
 
 #[cfg(test)]
-mod test {
+pub(crate) mod test {
     use super::*;
     pub use crate::backend::rpc::{RawRpcFuture, RawRpcSubscription};
     pub use crate::{backend::StorageResponse, error::RpcError};
@@ -348,6 +348,27 @@ mod test {
     pub type RpcResult<T> = Result<T, RpcError>;
     pub type Item = RpcResult<String>;
 
+    /// An [`crate::OnlineClient`] backed by a [`legacy::LegacyBackend`] talking to the given
+    /// mock RPC client, using the small Polkadot metadata and a zeroed genesis hash.
+    pub(crate) fn mock_online_client(
+        rpc_client: rpc_client::MockRpcClient,
+    ) -> crate::OnlineClient<crate::PolkadotConfig> {
+        let metadata_bytes = include_bytes!("../../../artifacts/polkadot_metadata_small.scale");
+        let metadata = crate::Metadata::decode(&mut &metadata_bytes[..]).unwrap();
+        let runtime_version = crate::client::RuntimeVersion {
+            spec_version: 0,
+            transaction_version: 0,
+        };
+        let backend = crate::backend::legacy::LegacyBackend::builder().build(rpc_client);
+        crate::OnlineClient::from_backend_with(
+            H256::zero(),
+            runtime_version,
+            metadata,
+            std::sync::Arc::new(backend),
+        )
+        .unwrap()
+    }
+
     fn random_hash() -> H256 {
         H256::random()
     }
@@ -615,6 +636,35 @@ mod test {
         type AssetId = u32;
     }
 
+    #[tokio::test]
+    async fn rpc_client_call_custom_method() {
+        use rpc_client::MockRpcBuilder;
+
+        // Echo back whatever params were given, so that we can check how they were sent:
+        let rpc_client: rpc::RpcClient = MockRpcBuilder::default()
+            .add_method("custom_echo", |_, _, params| {
+                Box::pin(async move {
+                    let params = params.map(|p| p.get().to_string());
+                    let params = params.unwrap_or_else(|| "\"none\"".to_string());
+                    Ok(RawValue::from_string(params).unwrap())
+                })
+            })
+            .build()
+            .into();
+
+        let res: (u32, String) = rpc_client.call("custom_echo", (1u32, "foo")).await.unwrap();
+        assert_eq!(res, (1, "foo".to_string()));
+
+        let res: HashMap<String, bool> = rpc_client
+            .call("custom_echo", HashMap::from([("flag", true)]))
+            .await
+            .unwrap();
+        assert_eq!(res, HashMap::from([("flag".to_string(), true)]));
+
+        let res: String = rpc_client.call("custom_echo", ()).await.unwrap();
+        assert_eq!(res, "none");
+    }
+
     mod legacy {
         use super::*;
         use crate::backend::{
@@ -662,6 +712,47 @@ mod test {
             Ok(Some(Bytes(str.into())))
         }
 
+        fn header(number: u32) -> <Conf as Config>::Header {
+            crate::config::substrate::SubstrateHeader {
+                parent_hash: H256::zero(),
+                number,
+                state_root: H256::zero(),
+                extrinsics_root: H256::zero(),
+                digest: Default::default(),
+            }
+        }
+
+        /// The block number whose hash is handed back by [`add_blocks()`] when the hash of
+        /// the latest block is asked for.
+        const BEST_BLOCK_NUMBER: u64 = 123;
+
+        /// Add `chain_getBlockHash` and `chain_getHeader` methods, where every block number
+        /// `n` has the hash `H256::from_low_u64_le(n)` and a header with the matching number,
+        /// apart from the `missing` block numbers, which have no hash.
+        fn add_blocks(builder: MockRpcBuilder, missing: &'static [u64]) -> MockRpcBuilder {
+            builder
+                .add_method("chain_getBlockHash", move |_, _, params| {
+                    Box::pin(async move {
+                        let params = params.map(|p| p.get().to_string());
+                        let rpc_params = jsonrpsee::types::Params::new(params.as_deref());
+                        let number: Option<u64> = rpc_params.sequence().optional_next().unwrap();
+                        let number = number.unwrap_or(BEST_BLOCK_NUMBER);
+                        let hash =
+                            (!missing.contains(&number)).then(|| H256::from_low_u64_le(number));
+                        Ok(RawValue::from_string(serde_json::to_string(&hash).unwrap()).unwrap())
+                    })
+                })
+                .add_method("chain_getHeader", |_, _, params| {
+                    Box::pin(async move {
+                        let params = params.map(|p| p.get().to_string());
+                        let rpc_params = jsonrpsee::types::Params::new(params.as_deref());
+                        let hash: H256 = rpc_params.sequence().next().unwrap();
+                        let header = header(hash.to_low_u64_le() as u32);
+                        Ok(RawValue::from_string(serde_json::to_string(&header).unwrap()).unwrap())
+                    })
+                })
+        }
+
         #[tokio::test]
         async fn storage_fetch_values() {
             let mock_data = vec![
@@ -767,6 +858,32 @@ mod test {
             assert_eq!(hash, response)
         }
 
+        #[tokio::test]
+        async fn submit_transaction_is_not_retried() {
+            use std::sync::atomic::{AtomicUsize, Ordering};
+
+            // Retrying a submission could submit the same transaction twice, so even
+            // a transient error should be handed straight back to the caller.
+            let attempts = Arc::new(AtomicUsize::new(0));
+            let attempts_in_handler = attempts.clone();
+            let rpc_client = MockRpcBuilder::default()
+                .add_subscription("author_submitAndWatchExtrinsic", move |_, _, _| {
+                    attempts_in_handler.fetch_add(1, Ordering::SeqCst);
+                    Box::pin(async move {
+                        Err(RpcError::DisconnectedWillReconnect(
+                            "Reconnecting".to_string(),
+                        ))
+                    })
+                })
+                .build();
+
+            let backend: LegacyBackend<Conf> = LegacyBackend::builder().build(rpc_client);
+            let err = backend.submit_transaction(&[0u8]).await.err().unwrap();
+
+            assert!(err.is_disconnected_will_reconnect());
+            assert_eq!(attempts.load(Ordering::SeqCst), 1);
+        }
+
         #[tokio::test]
         /// This test should cover the logic of the following methods:
         /// - `stream_runtime_version`
@@ -866,6 +983,974 @@ mod test {
             assert!(expected.is_empty());
             assert!(results.next().await.is_none())
         }
+
+        #[tokio::test]
+        async fn storage_batch_preserves_key_order() {
+            use crate::backend::legacy::{rpc_methods::StorageChangeSet, LegacyRpcMethods};
+
+            // The node hands back changes in a different order to the keys we ask
+            // for, and omits the key that has no value:
+            let change_set = StorageChangeSet {
+                block: random_hash(),
+                changes: vec![
+                    (Bytes(b"ID3".to_vec()), Some(Bytes(b"Data3".to_vec()))),
+                    (Bytes(b"ID1".to_vec()), Some(Bytes(b"Data1".to_vec()))),
+                    (Bytes(b"ID4".to_vec()), None),
+                ],
+            };
+            let mock_data = vec![(
+                "state_queryStorageAt",
+                Message::Single(Ok(vec![change_set])),
+            )];
+            let rpc_client = setup_mock_rpc()
+                .add_method("state_queryStorageAt", |data, _, _| {
+                    Box::pin(async move {
+                        let value = data.pop("state_queryStorageAt".into()).unwrap_single();
+                        value.map(|v| serde_json::value::RawValue::from_string(v).unwrap())
+                    })
+                })
+                .add_mock_data(mock_data)
+                .build();
+
+            // Test
+            let methods = LegacyRpcMethods::<Conf>::new(rpc_client.into());
+            let keys: [&[u8]; 4] = [b"ID1", b"ID2", b"ID3", b"ID4"];
+            let response = methods.storage_batch(keys, None).await.unwrap();
+
+            assert_eq!(
+                response,
+                vec![Some(b"Data1".to_vec()), None, Some(b"Data3".to_vec()), None]
+            );
+        }
+
+        #[tokio::test]
+        async fn state_get_keys_stream_dedupes_page_boundaries() {
+            use crate::backend::legacy::LegacyRpcMethods;
+
+            // The second page repeats the last key of the first page, and the
+            // third page is short, which should end the stream.
+            let mock_data = vec![
+                (
+                    "state_getKeysPaged",
+                    Message::Single(Ok(vec![Bytes(b"K1".to_vec()), Bytes(b"K2".to_vec())])),
+                ),
+                (
+                    "state_getKeysPaged",
+                    Message::Single(Ok(vec![Bytes(b"K2".to_vec()), Bytes(b"K3".to_vec())])),
+                ),
+                (
+                    "state_getKeysPaged",
+                    Message::Single(Ok(vec![Bytes(b"K4".to_vec())])),
+                ),
+            ];
+            let rpc_client = setup_mock_rpc()
+                .add_method("state_getKeysPaged", |data, _, _| {
+                    Box::pin(async move {
+                        let value = data.pop("state_getKeysPaged".into()).unwrap_single();
+                        value.map(|v| serde_json::value::RawValue::from_string(v).unwrap())
+                    })
+                })
+                .add_mock_data(mock_data)
+                .build();
+
+            // Test
+            let methods = LegacyRpcMethods::<Conf>::new(rpc_client.into());
+            let keys: Vec<Vec<u8>> = methods
+                .state_get_keys_stream(b"K", 2, None)
+                .map(|k| k.unwrap())
+                .collect()
+                .await;
+
+            assert_eq!(
+                keys,
+                vec![
+                    b"K1".to_vec(),
+                    b"K2".to_vec(),
+                    b"K3".to_vec(),
+                    b"K4".to_vec()
+                ]
+            );
+        }
+
+        #[tokio::test]
+        async fn state_count_keys_pages_at_one_block() {
+            use crate::backend::legacy::LegacyRpcMethods;
+
+            // There are 2500 keys, each being the big endian bytes of its index, and every
+            // page should be asked for at the best block hash that was first looked up.
+            let best_hash = H256::from_low_u64_le(BEST_BLOCK_NUMBER);
+            let rpc_client = add_blocks(MockRpcBuilder::default(), &[])
+                .add_method("state_getKeysPaged", move |_, _, params| {
+                    Box::pin(async move {
+                        let params = params.map(|p| p.get().to_string());
+                        let rpc_params = jsonrpsee::types::Params::new(params.as_deref());
+                        let mut rpc_params = rpc_params.sequence();
+                        let _prefix: Bytes = rpc_params.next().unwrap();
+                        let count: u32 = rpc_params.next().unwrap();
+                        let start_key: Option<Bytes> = rpc_params.next().unwrap();
+                        let at: H256 = rpc_params.next().unwrap();
+                        assert_eq!(at, best_hash);
+
+                        let start = start_key
+                            .map(|k| u32::from_be_bytes(k.0.try_into().unwrap()) + 1)
+                            .unwrap_or(0);
+                        let keys: Vec<Bytes> = (start..2500)
+                            .take(count as usize)
+                            .map(|n| Bytes(n.to_be_bytes().to_vec()))
+                            .collect();
+                        Ok(RawValue::from_string(serde_json::to_string(&keys).unwrap()).unwrap())
+                    })
+                })
+                .build();
+
+            // Test
+            let methods = LegacyRpcMethods::<Conf>::new(rpc_client.into());
+            let count = methods.state_count_keys(b"", None).await.unwrap();
+            assert_eq!(count, 2500);
+        }
+
+        #[tokio::test]
+        async fn dry_run_decoded_checks_module_error_against_metadata() {
+            use crate::backend::legacy::{rpc_methods::DryRunResult, LegacyRpcMethods};
+            use crate::error::{DispatchError, MetadataError};
+
+            let metadata_bytes = include_bytes!("../../../artifacts/polkadot_metadata_small.scale");
+            let metadata = Metadata::decode(&mut &metadata_bytes[..]).unwrap();
+            let pallet = metadata
+                .pallets()
+                .find(|p| p.error_variants().is_some_and(|v| !v.is_empty()))
+                .unwrap();
+
+            // Ok(Err(DispatchError::Module { index, error })) for a known pallet error,
+            // and then for an error in a pallet that doesn't exist:
+            let known = Bytes(vec![0, 1, 3, pallet.index(), 0, 0, 0, 0]);
+            let unknown = Bytes(vec![0, 1, 3, 255, 0, 0, 0, 0]);
+            let mock_data = vec![
+                ("system_dryRun", Message::Single(Ok(known))),
+                ("system_dryRun", Message::Single(Ok(unknown))),
+            ];
+            let rpc_client = setup_mock_rpc()
+                .add_method("system_dryRun", |data, _, _| {
+                    Box::pin(async move {
+                        let value = data.pop("system_dryRun".into()).unwrap_single();
+                        value.map(|v| serde_json::value::RawValue::from_string(v).unwrap())
+                    })
+                })
+                .add_mock_data(mock_data)
+                .build();
+
+            // Test
+            let methods = LegacyRpcMethods::<Conf>::new(rpc_client.into());
+
+            let result = methods.dry_run_decoded(&[], None, &metadata).await.unwrap();
+            let DryRunResult::DispatchError(DispatchError::Module(module_error)) = result else {
+                panic!("expected a module error, got {result:?}");
+            };
+            assert_eq!(module_error.details().unwrap().pallet.name(), pallet.name());
+
+            let err = methods
+                .dry_run_decoded(&[], None, &metadata)
+                .await
+                .unwrap_err();
+            assert!(matches!(
+                err,
+                crate::Error::Metadata(MetadataError::PalletIndexNotFound(255))
+            ));
+        }
+
+        #[tokio::test]
+        async fn chain_get_headers_in_range_preserves_order() {
+            use crate::backend::legacy::LegacyRpcMethods;
+
+            // Block #5 has no hash; every other block number `n` has the hash
+            // `H256::from_low_u64_le(n)` and a header with the matching number.
+            let rpc_client = add_blocks(MockRpcBuilder::default(), &[5]).build();
+
+            // Test
+            let methods = LegacyRpcMethods::<Conf>::new(rpc_client.into());
+
+            let headers = methods.chain_get_headers_in_range(0, 4).await.unwrap();
+            let numbers: Vec<u32> = headers.iter().map(|h| h.number).collect();
+            assert_eq!(numbers, vec![0, 1, 2, 3, 4]);
+
+            let err = methods.chain_get_headers_in_range(3, 8).await.unwrap_err();
+            assert!(err.to_string().contains("#5"), "unexpected error: {err}");
+        }
+
+        #[tokio::test]
+        async fn chain_subscribe_finalized_heads_from() {
+            use crate::backend::legacy::LegacyRpcMethods;
+
+            // Every block number `n` has the hash `H256::from_low_u64_le(n)` and a header
+            // with the matching number.
+            let rpc_client = add_blocks(MockRpcBuilder::default(), &[])
+                .add_subscription("chain_subscribeFinalizedHeads", |_, _, _| {
+                    Box::pin(async move {
+                        // The node repeats block #6 and goes back to #5, neither of
+                        // which should be handed back again.
+                        let headers = [6, 6, 5, 7].map(|n| {
+                            Ok(
+                                RawValue::from_string(serde_json::to_string(&header(n)).unwrap())
+                                    .unwrap(),
+                            )
+                        });
+                        Ok(RawRpcSubscription {
+                            stream: futures::stream::iter(headers).boxed(),
+                            id: Some("ID".to_string()),
+                        })
+                    })
+                })
+                .build();
+
+            // Test
+            let methods = LegacyRpcMethods::<Conf>::new(rpc_client.into());
+
+            let numbers: Vec<u32> = methods
+                .chain_subscribe_finalized_heads_from(H256::from_low_u64_le(3))
+                .await
+                .unwrap()
+                .map(|h| h.unwrap().number)
+                .collect()
+                .await;
+            assert_eq!(numbers, vec![3, 4, 5, 6, 7]);
+        }
+
+        #[tokio::test]
+        async fn chain_subscribe_finalized_blocks() {
+            use crate::backend::legacy::LegacyRpcMethods;
+            use crate::config::Header as _;
+
+            // Every block number `n` has the hash `H256::from_low_u64_le(n)` when asking for
+            // it by number, and blocks are fetched using the hash of their header.
+            let rpc_client = add_blocks(MockRpcBuilder::default(), &[])
+                .add_method("chain_getBlock", |_, _, params| {
+                    Box::pin(async move {
+                        let params = params.map(|p| p.get().to_string());
+                        let rpc_params = jsonrpsee::types::Params::new(params.as_deref());
+                        let hash: H256 = rpc_params.sequence().next().unwrap();
+                        let number = (0..10).find(|&n| header(n).hash() == hash).unwrap();
+                        let block = serde_json::json!({
+                            "block": {
+                                "header": header(number),
+                                "extrinsics": [format!("0x{:02x}", number)],
+                            },
+                            "justifications": null,
+                        });
+                        Ok(RawValue::from_string(block.to_string()).unwrap())
+                    })
+                })
+                .add_subscription("chain_subscribeFinalizedHeads", |_, _, _| {
+                    Box::pin(async move {
+                        // The node skips over block #4, which should be filled in.
+                        let headers = [3, 5].map(|n| {
+                            Ok(
+                                RawValue::from_string(serde_json::to_string(&header(n)).unwrap())
+                                    .unwrap(),
+                            )
+                        });
+                        Ok(RawRpcSubscription {
+                            stream: futures::stream::iter(headers).boxed(),
+                            id: Some("ID".to_string()),
+                        })
+                    })
+                })
+                .build();
+
+            // Test
+            let methods = LegacyRpcMethods::<Conf>::new(rpc_client.into());
+
+            let blocks: Vec<(u32, Vec<u8>)> = methods
+                .chain_subscribe_finalized_blocks()
+                .await
+                .unwrap()
+                .map(|b| {
+                    let block = b.unwrap().block;
+                    (block.header.number, block.extrinsics[0].0.clone())
+                })
+                .collect()
+                .await;
+            assert_eq!(blocks, vec![(3, vec![3]), (4, vec![4]), (5, vec![5])]);
+        }
+
+        #[tokio::test]
+        async fn filling_in_gaps_reports_missing_blocks() {
+            use crate::backend::legacy::{
+                subscribe_to_block_headers_filling_in_gaps, LegacyRpcMethods,
+            };
+            use futures::TryStreamExt;
+
+            // Block #2 has no hash; every other block number `n` has the hash
+            // `H256::from_low_u64_le(n)` and a header with the matching number.
+            let rpc_client = add_blocks(MockRpcBuilder::default(), &[2]).build();
+
+            // Test
+            let methods = LegacyRpcMethods::<Conf>::new(rpc_client.into());
+            let finalized = futures::stream::iter([Ok::<_, Error>(header(3))]);
+
+            let results: Vec<Result<u32, Error>> =
+                subscribe_to_block_headers_filling_in_gaps(methods, finalized, Some(0))
+                    .map_ok(|h| h.number)
+                    .collect()
+                    .await;
+
+            assert_eq!(results.len(), 3);
+            assert_eq!(results[0].as_ref().unwrap(), &1);
+            let err = results[1].as_ref().unwrap_err();
+            assert!(err.to_string().contains("#2"), "unexpected error: {err}");
+            assert_eq!(results[2].as_ref().unwrap(), &3);
+        }
+
+        #[tokio::test]
+        async fn chain_get_finalized_block_number() {
+            use crate::backend::legacy::LegacyRpcMethods;
+
+            let finalized = H256::from_low_u64_le(7);
+            let pruned = H256::from_low_u64_le(8);
+            let mock_data = vec![
+                ("chain_getFinalizedHead", Message::Single(Ok(finalized))),
+                ("chain_getFinalizedHead", Message::Single(Ok(pruned))),
+            ];
+            let rpc_client = MockRpcBuilder::default()
+                .add_method("chain_getFinalizedHead", |data, _, _| {
+                    Box::pin(async move {
+                        let value = data.pop("chain_getFinalizedHead".into()).unwrap_single();
+                        value.map(|v| RawValue::from_string(v).unwrap())
+                    })
+                })
+                .add_method("chain_getHeader", |_, _, params| {
+                    Box::pin(async move {
+                        let params = params.map(|p| p.get().to_string());
+                        let rpc_params = jsonrpsee::types::Params::new(params.as_deref());
+                        let hash: H256 = rpc_params.sequence().next().unwrap();
+                        // Pretend that the header for block #8 has been pruned:
+                        let number = hash.to_low_u64_le() as u32;
+                        let header = (number != 8).then(|| header(number));
+                        Ok(RawValue::from_string(serde_json::to_string(&header).unwrap()).unwrap())
+                    })
+                })
+                .add_mock_data(mock_data)
+                .build();
+
+            // Test
+            let methods = LegacyRpcMethods::<Conf>::new(rpc_client.into());
+
+            let number = methods.chain_get_finalized_block_number().await.unwrap();
+            assert_eq!(number, 7);
+
+            let err = methods
+                .chain_get_finalized_block_number()
+                .await
+                .unwrap_err();
+            let crate::Error::Block(crate::error::BlockError::NotFound(hash)) = err else {
+                panic!("expected a block not found error, got {err:?}");
+            };
+            assert_eq!(hash, format!("{pruned:?}"));
+        }
+
+        #[tokio::test]
+        async fn system_sync_state_and_peers() {
+            use crate::backend::legacy::{rpc_methods::SystemPeerInfo, LegacyRpcMethods};
+
+            let rpc_client = MockRpcBuilder::default()
+                .add_method("system_syncState", |_, _, _| {
+                    Box::pin(async move {
+                        let json = r#"{"startingBlock":1,"currentBlock":20,"highestBlock":300}"#;
+                        Ok(RawValue::from_string(json.to_string()).unwrap())
+                    })
+                })
+                .add_method("system_peers", |_, _, _| {
+                    Box::pin(async move {
+                        let json = serde_json::json!([{
+                            "peerId": "12D3KooWPeer",
+                            "roles": "FULL",
+                            "bestHash": H256::from_low_u64_le(5),
+                            "bestNumber": 5,
+                        }]);
+                        Ok(RawValue::from_string(json.to_string()).unwrap())
+                    })
+                })
+                .build();
+
+            // Test
+            let methods = LegacyRpcMethods::<Conf>::new(rpc_client.into());
+
+            let sync_state = methods.system_sync_state().await.unwrap();
+            assert_eq!(sync_state.starting_block, 1);
+            assert_eq!(sync_state.current_block, 20);
+            assert_eq!(sync_state.highest_block, 300);
+
+            let peers = methods.system_peers().await.unwrap();
+            assert_eq!(
+                peers,
+                vec![SystemPeerInfo {
+                    peer_id: "12D3KooWPeer".to_string(),
+                    roles: "FULL".to_string(),
+                    best_hash: H256::from_low_u64_le(5),
+                    best_number: 5,
+                }]
+            );
+        }
+
+        #[tokio::test]
+        async fn dry_run_batch_pins_best_block() {
+            use crate::backend::legacy::LegacyRpcMethods;
+
+            let best = H256::from_low_u64_le(1);
+            let mock_data = vec![
+                ("chain_getBlockHash", Message::Single(Ok(Some(best)))),
+                // If a second lookup were to happen, it'd see a new best block:
+                (
+                    "chain_getBlockHash",
+                    Message::Single(Ok(Some(H256::from_low_u64_le(2)))),
+                ),
+            ];
+            let rpc_client = setup_mock_rpc()
+                .add_method("system_dryRun", |_, _, params| {
+                    Box::pin(async move {
+                        // Echo back the extrinsic bytes and the block they were run at:
+                        let params = params.map(|p| p.get().to_string());
+                        let rpc_params = jsonrpsee::types::Params::new(params.as_deref());
+                        let mut seq = rpc_params.sequence();
+                        let xt: Bytes = seq.next().unwrap();
+                        let at: H256 = seq.next().unwrap();
+                        let mut result = xt.0;
+                        result.push(at.to_low_u64_le() as u8);
+                        Ok(
+                            RawValue::from_string(serde_json::to_string(&Bytes(result)).unwrap())
+                                .unwrap(),
+                        )
+                    })
+                })
+                .add_mock_data(mock_data)
+                .build();
+
+            // Test
+            let methods = LegacyRpcMethods::<Conf>::new(rpc_client.into());
+            let xts: [&[u8]; 3] = [&[10], &[20], &[30]];
+
+            let results = methods.dry_run_batch(xts, None).await.unwrap();
+            let results: Vec<Vec<u8>> = results.into_iter().map(|r| r.0).collect();
+            assert_eq!(results, vec![vec![10, 1], vec![20, 1], vec![30, 1]]);
+
+            let at = H256::from_low_u64_le(9);
+            let results = methods.dry_run_batch(xts, Some(at)).await.unwrap();
+            let results: Vec<Vec<u8>> = results.into_iter().map(|r| r.0).collect();
+            assert_eq!(results, vec![vec![10, 9], vec![20, 9], vec![30, 9]]);
+        }
+
+        #[tokio::test]
+        async fn system_ss58_prefix() {
+            use crate::backend::legacy::LegacyRpcMethods;
+
+            let mock_data = vec![
+                (
+                    "system_properties",
+                    Message::Single(Ok(
+                        serde_json::json!({ "ss58Format": 2, "tokenDecimals": 12 }),
+                    )),
+                ),
+                (
+                    "system_properties",
+                    Message::Single(Ok(serde_json::json!({ "tokenDecimals": 12 }))),
+                ),
+                (
+                    "system_properties",
+                    Message::Single(Ok(serde_json::json!({ "ss58Format": "nope" }))),
+                ),
+            ];
+            let rpc_client = MockRpcBuilder::default()
+                .add_method("system_properties", |data, _, _| {
+                    Box::pin(async move {
+                        let value = data.pop("system_properties".into()).unwrap_single();
+                        value.map(|v| RawValue::from_string(v).unwrap())
+                    })
+                })
+                .add_mock_data(mock_data)
+                .build();
+
+            // Test
+            let methods = LegacyRpcMethods::<Conf>::new(rpc_client.into());
+
+            assert_eq!(methods.system_ss58_prefix().await.unwrap(), 2);
+            // Default to the generic Substrate prefix if the chain doesn't give one:
+            assert_eq!(methods.system_ss58_prefix().await.unwrap(), 42);
+            assert!(methods.system_ss58_prefix().await.is_err());
+        }
+
+        #[tokio::test]
+        async fn state_get_metadata_rejects_old_versions() {
+            use crate::backend::legacy::LegacyRpcMethods;
+            use crate::error::MetadataTryFromError;
+            use codec::Encode;
+
+            // V13 metadata, which (without the "legacy" feature) is just opaque bytes to us:
+            let mut v13 = frame_metadata::META_RESERVED.encode();
+            v13.push(13);
+            v13.extend(vec![1u8, 2, 3].encode());
+
+            let mock_data = vec![
+                ("state_getMetadata", Message::Single(Ok(Bytes(v13.clone())))),
+                ("state_getMetadata", Message::Single(Ok(Bytes(v13)))),
+            ];
+            let rpc_client = MockRpcBuilder::default()
+                .add_method("state_getMetadata", |data, _, _| {
+                    Box::pin(async move {
+                        let value = data.pop("state_getMetadata".into()).unwrap_single();
+                        value.map(|v| RawValue::from_string(v).unwrap())
+                    })
+                })
+                .add_mock_data(mock_data)
+                .build();
+
+            // Test
+            let methods = LegacyRpcMethods::<Conf>::new(rpc_client.into());
+
+            assert_eq!(methods.state_get_metadata_version(None).await.unwrap(), 13);
+
+            let err = methods.state_get_metadata(None).await.unwrap_err();
+            assert!(
+                matches!(
+                    err,
+                    crate::Error::MetadataDecoding(
+                        MetadataTryFromError::UnsupportedMetadataVersion(13)
+                    )
+                ),
+                "unexpected error: {err:?}"
+            );
+        }
+
+        #[tokio::test]
+        async fn state_get_storage_decoded() {
+            use crate::backend::legacy::LegacyRpcMethods;
+            use codec::Encode;
+
+            let mock_data = vec![
+                ("ID1", Message::Single(Ok(Some(Bytes(1234u32.encode()))))),
+                ("ID2", Message::Single(Ok(None::<Bytes>))),
+                ("ID3", Message::Single(Ok(Some(Bytes(vec![1, 2]))))),
+            ];
+            let rpc_client = setup_mock_rpc().add_mock_data(mock_data).build();
+
+            // Test
+            let methods = LegacyRpcMethods::<Conf>::new(rpc_client.into());
+
+            let value: Option<u32> = methods
+                .state_get_storage_decoded(b"ID1", None)
+                .await
+                .unwrap();
+            assert_eq!(value, Some(1234));
+
+            let value: Option<u32> = methods
+                .state_get_storage_decoded(b"ID2", None)
+                .await
+                .unwrap();
+            assert_eq!(value, None);
+
+            // Too few bytes to be a u32:
+            let err = methods
+                .state_get_storage_decoded::<u32>(b"ID3", None)
+                .await
+                .unwrap_err();
+            assert!(
+                err.to_string().contains("0x494433"),
+                "unexpected error: {err}"
+            );
+        }
+
+        #[tokio::test]
+        async fn state_call_decoded() {
+            use crate::backend::legacy::LegacyRpcMethods;
+            use codec::Encode;
+
+            let rpc_client = MockRpcBuilder::default()
+                .add_method("state_call", |_, _, params| {
+                    Box::pin(async move {
+                        let params = params.map(|p| p.get().to_string());
+                        let rpc_params = jsonrpsee::types::Params::new(params.as_deref());
+                        let mut seq = rpc_params.sequence();
+                        let function: String = seq.next().unwrap();
+                        let call_parameters: Bytes = seq.next().unwrap();
+                        // Respond with the call parameter length plus one to show they were passed.
+                        let result = match function.as_str() {
+                            "AccountNonceApi_account_nonce" => {
+                                (call_parameters.0.len() as u32 + 1).encode()
+                            }
+                            _ => vec![1],
+                        };
+                        let result = serde_json::to_string(&Bytes(result)).unwrap();
+                        Ok(RawValue::from_string(result).unwrap())
+                    })
+                })
+                .build();
+
+            let methods = LegacyRpcMethods::<Conf>::new(rpc_client.into());
+
+            let nonce: u32 = methods
+                .state_call_decoded("AccountNonceApi_account_nonce", Some(&[0; 32]), None)
+                .await
+                .unwrap();
+            assert_eq!(nonce, 33);
+
+            let err = methods
+                .state_call_decoded::<u32>("Other_api", None, None)
+                .await
+                .unwrap_err();
+            assert!(
+                err.to_string().contains("Other_api"),
+                "unexpected error: {err}"
+            );
+        }
+
+        #[tokio::test]
+        async fn query_fee_info_handles_both_weight_versions() {
+            use crate::backend::legacy::rpc_methods::RuntimeDispatchInfo;
+            use crate::backend::legacy::LegacyRpcMethods;
+            use crate::utils::{DispatchClass, Weight};
+            use codec::{Compact, Encode};
+
+            let runtime_version = |payment_api_version: u32| {
+                serde_json::json!({
+                    "specVersion": 1,
+                    "transactionVersion": 1,
+                    // The ID is blake2_64("TransactionPaymentApi").
+                    "apis": [["0x37c8bb1350a9a2a8", payment_api_version]],
+                })
+            };
+            // Version 1 of the API hands back the weight as a single u64, and newer
+            // versions hand back a two dimensional weight.
+            let v1_info = (10u64, 1u8, 100u128).encode();
+            let v2_info = (Compact(10u64), Compact(20u64), 2u8, 100u128).encode();
+
+            let rpc_client = setup_mock_rpc()
+                .add_method("state_getRuntimeVersion", |data, _, _| {
+                    Box::pin(async move {
+                        let value = data.pop("state_getRuntimeVersion".into()).unwrap_single();
+                        value.map(|v| RawValue::from_string(v).unwrap())
+                    })
+                })
+                .add_method("state_call", |data, _, params| {
+                    Box::pin(async move {
+                        let params = params.map(|p| p.get().to_string());
+                        let rpc_params = jsonrpsee::types::Params::new(params.as_deref());
+                        let mut seq = rpc_params.sequence();
+                        let function: String = seq.next().unwrap();
+                        let call_parameters: Bytes = seq.next().unwrap();
+                        assert_eq!(function, "TransactionPaymentApi_query_info");
+                        // The extrinsic is followed by its u32 encoded length.
+                        assert_eq!(call_parameters.0, vec![7, 1, 0, 0, 0]);
+                        let value = data.pop("state_call".into()).unwrap_single();
+                        value.map(|v| RawValue::from_string(v).unwrap())
+                    })
+                })
+                .add_mock_data([
+                    (
+                        "chain_getBlockHash",
+                        Message::Single(Ok(Some(random_hash()))),
+                    ),
+                    (
+                        "chain_getBlockHash",
+                        Message::Single(Ok(Some(random_hash()))),
+                    ),
+                ])
+                .add_mock_data([
+                    (
+                        "state_getRuntimeVersion",
+                        Message::Single(Ok(runtime_version(1))),
+                    ),
+                    (
+                        "state_getRuntimeVersion",
+                        Message::Single(Ok(runtime_version(4))),
+                    ),
+                ])
+                .add_mock_data([
+                    ("state_call", Message::Single(Ok(Bytes(v1_info)))),
+                    ("state_call", Message::Single(Ok(Bytes(v2_info)))),
+                ])
+                .build();
+
+            let methods = LegacyRpcMethods::<Conf>::new(rpc_client.into());
+
+            let info = methods.query_fee_info(&[7], None).await.unwrap();
+            assert_eq!(
+                info,
+                RuntimeDispatchInfo {
+                    weight: Weight {
+                        ref_time: 10,
+                        proof_size: 0
+                    },
+                    class: DispatchClass::Operational,
+                    partial_fee: 100,
+                }
+            );
+
+            let info = methods.query_fee_info(&[7], None).await.unwrap();
+            assert_eq!(
+                info,
+                RuntimeDispatchInfo {
+                    weight: Weight {
+                        ref_time: 10,
+                        proof_size: 20
+                    },
+                    class: DispatchClass::Mandatory,
+                    partial_fee: 100,
+                }
+            );
+        }
+
+        #[tokio::test]
+        async fn query_fee_details_with_and_without_inclusion_fee() {
+            use crate::backend::legacy::rpc_methods::{FeeDetails, InclusionFee};
+            use crate::backend::legacy::LegacyRpcMethods;
+            use codec::Encode;
+
+            let inclusion_fee = Some((1u128, 2u128, 3u128));
+            let with_inclusion_fee = (inclusion_fee, 4u128).encode();
+            let without_inclusion_fee = (None::<(u128, u128, u128)>, 0u128).encode();
+
+            let rpc_client = setup_mock_rpc()
+                .add_method("state_call", |data, _, params| {
+                    Box::pin(async move {
+                        let params = params.map(|p| p.get().to_string());
+                        let rpc_params = jsonrpsee::types::Params::new(params.as_deref());
+                        let mut seq = rpc_params.sequence();
+                        let function: String = seq.next().unwrap();
+                        let call_parameters: Bytes = seq.next().unwrap();
+                        assert_eq!(function, "TransactionPaymentApi_query_fee_details");
+                        // The extrinsic is followed by its u32 encoded length.
+                        assert_eq!(call_parameters.0, vec![7, 1, 0, 0, 0]);
+                        let value = data.pop("state_call".into()).unwrap_single();
+                        value.map(|v| RawValue::from_string(v).unwrap())
+                    })
+                })
+                .add_mock_data([
+                    ("state_call", Message::Single(Ok(Bytes(with_inclusion_fee)))),
+                    (
+                        "state_call",
+                        Message::Single(Ok(Bytes(without_inclusion_fee))),
+                    ),
+                ])
+                .build();
+
+            let methods = LegacyRpcMethods::<Conf>::new(rpc_client.into());
+
+            let details = methods.query_fee_details(&[7], None).await.unwrap();
+            assert_eq!(
+                details,
+                FeeDetails {
+                    inclusion_fee: Some(InclusionFee {
+                        base_fee: 1,
+                        len_fee: 2,
+                        adjusted_weight_fee: 3,
+                    }),
+                    tip: 4,
+                }
+            );
+            assert_eq!(details.final_fee(), 10);
+
+            let details = methods.query_fee_details(&[7], None).await.unwrap();
+            assert_eq!(
+                details,
+                FeeDetails {
+                    inclusion_fee: None,
+                    tip: 0,
+                }
+            );
+            assert_eq!(details.final_fee(), 0);
+        }
+
+        #[tokio::test]
+        async fn childstate_get_storage_and_keys_paged() {
+            use crate::backend::legacy::LegacyRpcMethods;
+
+            let child_key = b":child_storage:default:crowdloan".to_vec();
+            let at = H256::from_low_u64_le(123);
+
+            let expected_child_key = child_key.clone();
+            let rpc_client = MockRpcBuilder::default()
+                .add_method("childstate_getStorage", move |_, _, params| {
+                    let expected_child_key = expected_child_key.clone();
+                    Box::pin(async move {
+                        let params = params.map(|p| p.get().to_string());
+                        let rpc_params = jsonrpsee::types::Params::new(params.as_deref());
+                        let mut seq = rpc_params.sequence();
+                        let child_key: String = seq.next().unwrap();
+                        let key: String = seq.next().unwrap();
+                        let hash: Option<H256> = seq.next().unwrap();
+                        // The child key and key are both passed as hex with the prefix intact.
+                        assert_eq!(child_key, format!("0x{}", hex::encode(&expected_child_key)));
+                        assert_eq!(key, "0x0102");
+                        assert_eq!(hash, Some(at));
+                        Ok(RawValue::from_string("\"0x2a\"".to_string()).unwrap())
+                    })
+                })
+                .add_method("childstate_getKeysPaged", move |_, _, params| {
+                    Box::pin(async move {
+                        let params = params.map(|p| p.get().to_string());
+                        let rpc_params = jsonrpsee::types::Params::new(params.as_deref());
+                        let mut seq = rpc_params.sequence();
+                        let child_key: Bytes = seq.next().unwrap();
+                        let prefix: Bytes = seq.next().unwrap();
+                        let count: u32 = seq.next().unwrap();
+                        let start_key: Option<Bytes> = seq.next().unwrap();
+                        let hash: Option<H256> = seq.next().unwrap();
+                        assert_eq!(child_key.0, b":child_storage:default:crowdloan");
+                        assert_eq!(prefix.0, vec![1]);
+                        assert_eq!(count, 2);
+                        assert_eq!(start_key.map(|k| k.0), Some(vec![1, 0]));
+                        assert_eq!(hash, None);
+                        let keys = vec![Bytes(vec![1, 1]), Bytes(vec![1, 2])];
+                        Ok(RawValue::from_string(serde_json::to_string(&keys).unwrap()).unwrap())
+                    })
+                })
+                .build();
+
+            // Test
+            let methods = LegacyRpcMethods::<Conf>::new(rpc_client.into());
+
+            let value = methods
+                .childstate_get_storage(&child_key, &[1, 2], Some(at))
+                .await
+                .unwrap();
+            assert_eq!(value, Some(vec![42]));
+
+            let keys = methods
+                .childstate_get_keys_paged(&child_key, &[1], 2, Some(&[1, 0]), None)
+                .await
+                .unwrap();
+            assert_eq!(keys, vec![vec![1, 1], vec![1, 2]]);
+        }
+
+        #[tokio::test]
+        async fn state_get_storage_or_default() {
+            use crate::backend::legacy::LegacyRpcMethods;
+            use codec::Encode;
+
+            let mock_data = vec![
+                ("ID1", Message::Single(Ok(Some(Bytes(1234u32.encode()))))),
+                ("ID2", Message::Single(Ok(None::<Bytes>))),
+                ("ID3", Message::Single(Ok(None::<Bytes>))),
+            ];
+            let rpc_client = setup_mock_rpc().add_mock_data(mock_data).build();
+
+            // Test
+            let methods = LegacyRpcMethods::<Conf>::new(rpc_client.into());
+            let default = 0u32.encode();
+
+            let value: u32 = methods
+                .state_get_storage_or_default(b"ID1", &default, None)
+                .await
+                .unwrap();
+            assert_eq!(value, 1234);
+
+            let value: u32 = methods
+                .state_get_storage_or_default(b"ID2", &default, None)
+                .await
+                .unwrap();
+            assert_eq!(value, 0);
+
+            // Bad default bytes are reported, too:
+            let err = methods
+                .state_get_storage_or_default::<u32>(b"ID3", &[1], None)
+                .await
+                .unwrap_err();
+            assert!(
+                err.to_string().contains("default"),
+                "unexpected error: {err}"
+            );
+        }
+
+        #[tokio::test]
+        async fn chain_get_block_by_number() {
+            use crate::backend::legacy::LegacyRpcMethods;
+
+            // Block #1 exists, block #2 has no hash yet.
+            let rpc_client = add_blocks(MockRpcBuilder::default(), &[2])
+                .add_method("chain_getBlock", |_, _, params| {
+                    Box::pin(async move {
+                        let params = params.map(|p| p.get().to_string());
+                        let rpc_params = jsonrpsee::types::Params::new(params.as_deref());
+                        let hash: H256 = rpc_params.sequence().next().unwrap();
+                        let block = serde_json::json!({
+                            "block": {
+                                "header": header(hash.to_low_u64_le() as u32),
+                                "extrinsics": [Bytes(vec![1, 2, 3])],
+                            },
+                            "justifications": null,
+                        });
+                        Ok(RawValue::from_string(block.to_string()).unwrap())
+                    })
+                })
+                .build();
+
+            // Test
+            let methods = LegacyRpcMethods::<Conf>::new(rpc_client.into());
+
+            let block = methods
+                .chain_get_block_by_number(1u32)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(block.block.header.number, 1);
+            assert_eq!(block.block.extrinsics[0].0, vec![1, 2, 3]);
+
+            let block = methods.chain_get_block_by_number(2u32).await.unwrap();
+            assert!(block.is_none());
+        }
+
+        #[tokio::test]
+        async fn state_subscribe_storage() {
+            use crate::backend::legacy::{rpc_methods::StorageChangeSet, LegacyRpcMethods};
+
+            // Echo back the keys subscribed to, or a marker if no keys were sent at all.
+            let rpc_client = MockRpcBuilder::default()
+                .add_subscription("state_subscribeStorage", |_, _, params| {
+                    Box::pin(async move {
+                        let keys: Vec<Bytes> = match params {
+                            None => vec![Bytes(b"all".to_vec())],
+                            Some(params) => {
+                                let params = params.get().to_string();
+                                let rpc_params = jsonrpsee::types::Params::new(Some(&params));
+                                rpc_params.sequence().next().unwrap()
+                            }
+                        };
+                        let change_set = StorageChangeSet {
+                            block: H256::zero(),
+                            changes: keys.into_iter().map(|k| (k, None)).collect(),
+                        };
+                        let change_set =
+                            RawValue::from_string(serde_json::to_string(&change_set).unwrap())
+                                .unwrap();
+                        Ok(RawRpcSubscription {
+                            stream: futures::stream::iter([Ok(change_set)]).boxed(),
+                            id: Some("ID".to_string()),
+                        })
+                    })
+                })
+                .build();
+
+            // Test
+            let methods = LegacyRpcMethods::<Conf>::new(rpc_client.into());
+
+            let change_keys = |change_set: StorageChangeSet<H256>| {
+                change_set
+                    .changes
+                    .into_iter()
+                    .map(|(k, _)| k.0)
+                    .collect::<Vec<_>>()
+            };
+
+            let mut sub = methods
+                .state_subscribe_storage([&b"a"[..], &b"b"[..]])
+                .await
+                .unwrap();
+            let change_set = sub.next().await.unwrap().unwrap();
+            assert_eq!(change_keys(change_set), vec![b"a".to_vec(), b"b".to_vec()]);
+
+            // No keys means subscribing to everything, rather than to nothing.
+            let mut sub = methods.state_subscribe_storage([]).await.unwrap();
+            let change_set = sub.next().await.unwrap().unwrap();
+            assert_eq!(change_keys(change_set), vec![b"all".to_vec()]);
+        }
     }
 
     mod unstable_backend {
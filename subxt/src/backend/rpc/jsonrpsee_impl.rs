@@ -66,3 +66,30 @@ impl RpcClientT for Client {
         })
     }
 }
+
+/// An HTTP client can make requests but cannot subscribe to anything, so any
+/// attempt to subscribe will return [`RpcError::SubscriptionsUnsupported`].
+#[cfg(feature = "native")]
+impl RpcClientT for jsonrpsee::http_client::HttpClient {
+    fn request_raw<'a>(
+        &'a self,
+        method: &'a str,
+        params: Option<Box<RawValue>>,
+    ) -> RawRpcFuture<'a, Box<RawValue>> {
+        Box::pin(async move {
+            let res = ClientT::request(self, method, Params(params))
+                .await
+                .map_err(|e| RpcError::ClientError(Box::new(e)))?;
+            Ok(res)
+        })
+    }
+
+    fn subscribe_raw<'a>(
+        &'a self,
+        _sub: &'a str,
+        _params: Option<Box<RawValue>>,
+        _unsub: &'a str,
+    ) -> RawRpcFuture<'a, RawRpcSubscription> {
+        Box::pin(async { Err(RpcError::SubscriptionsUnsupported) })
+    }
+}
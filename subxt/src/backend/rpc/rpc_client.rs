@@ -39,6 +39,30 @@ impl RpcClient {
         Ok(Self::new(client))
     }
 
+    #[cfg(all(feature = "jsonrpsee", feature = "native"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "jsonrpsee", feature = "native"))))]
+    /// Create an RPC client which sends each request as an HTTP POST to some URL, currently
+    /// based on [`jsonrpsee`]. This avoids keeping a connection open, but subscriptions are
+    /// not available, and attempting to subscribe returns
+    /// [`crate::error::RpcError::SubscriptionsUnsupported`].
+    ///
+    /// Errors if an insecure URL is provided. In this case, use [`RpcClient::from_insecure_http_url`] instead.
+    pub fn from_http_url<U: AsRef<str>>(url: U) -> Result<Self, Error> {
+        crate::utils::validate_url_is_secure(url.as_ref())?;
+        RpcClient::from_insecure_http_url(url)
+    }
+
+    #[cfg(all(feature = "jsonrpsee", feature = "native"))]
+    /// Create an RPC client which sends each request as an HTTP POST to some URL, currently
+    /// based on [`jsonrpsee`]. See [`RpcClient::from_http_url`].
+    ///
+    /// Allows insecure URLs without SSL encryption, e.g. http:// URLs.
+    pub fn from_insecure_http_url<U: AsRef<str>>(url: U) -> Result<Self, Error> {
+        let client = jsonrpsee_helpers::http_client(url.as_ref())
+            .map_err(|e| crate::error::RpcError::ClientError(Box::new(e)))?;
+        Ok(Self::new(client))
+    }
+
     /// Create a new [`RpcClient`] from an arbitrary [`RpcClientT`] implementation.
     pub fn new<R: RpcClientT>(client: R) -> Self {
         RpcClient {
@@ -60,6 +84,34 @@ impl RpcClient {
         Ok(val)
     }
 
+    /// Make an RPC request to some arbitrary method, such as a custom RPC method exposed by
+    /// a specific chain, serializing the given parameters and deserializing the response.
+    ///
+    /// Parameters can be anything which serializes to a JSON array (such as a tuple, which
+    /// is the common case) or object. `()` serializes to `null`, and can be used to provide
+    /// no parameters at all.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn example(client: subxt::backend::rpc::RpcClient) -> Result<(), subxt::Error> {
+    /// let peers: Vec<serde_json::Value> = client.call("system_peers", ()).await?;
+    /// let hash: Option<String> = client.call("chain_getBlockHash", (1000,)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn call<Params: Serialize, Res: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Params,
+    ) -> Result<Res, Error> {
+        let params = serde_json::value::to_raw_value(&params)?;
+        let params = (params.get() != "null").then_some(params);
+        let res = self.client.request_raw(method, params).await?;
+        let val = serde_json::from_str(res.get())?;
+        Ok(val)
+    }
+
     /// Subscribe to an RPC endpoint, providing the parameters and the method to call to
     /// unsubscribe from it again.
     ///
@@ -249,6 +301,7 @@ mod jsonrpsee_helpers {
     pub use jsonrpsee::{
         client_transport::ws::{self, EitherStream, Url, WsTransportClientBuilder},
         core::client::{Client, Error},
+        http_client::{HttpClient, HttpClientBuilder},
     };
     use tokio_util::compat::Compat;
 
@@ -263,6 +316,11 @@ mod jsonrpsee_helpers {
             .build_with_tokio(sender, receiver))
     }
 
+    /// Build HTTP RPC client from URL
+    pub fn http_client(url: &str) -> Result<HttpClient, Error> {
+        HttpClientBuilder::default().build(url)
+    }
+
     async fn ws_transport(url: &str) -> Result<(Sender, Receiver), Error> {
         let url = Url::parse(url).map_err(|e| Error::Transport(e.into()))?;
         WsTransportClientBuilder::default()
@@ -290,3 +348,46 @@ mod jsonrpsee_helpers {
             .build_with_wasm(sender, receiver))
     }
 }
+
+#[cfg(all(test, feature = "jsonrpsee", feature = "native"))]
+mod test {
+    use super::*;
+    use crate::error::RpcError;
+    use jsonrpsee::server::{RpcModule, Server};
+
+    #[tokio::test]
+    async fn http_client_requests_but_does_not_subscribe() {
+        let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+        let addr = server.local_addr().unwrap();
+        let mut module = RpcModule::new(());
+        module
+            .register_method("say_hello", |params, _, _| {
+                let name: String = params.one().unwrap();
+                format!("hello {name}")
+            })
+            .unwrap();
+        let _handle = server.start(module);
+
+        let client = RpcClient::from_insecure_http_url(format!("http://{addr}")).unwrap();
+
+        let res: String = client
+            .request("say_hello", rpc_params!["bob"])
+            .await
+            .unwrap();
+        assert_eq!(res, "hello bob");
+
+        let sub = client
+            .subscribe::<String>("subscribe_hello", rpc_params![], "unsubscribe_hello")
+            .await;
+        assert!(matches!(
+            sub,
+            Err(Error::Rpc(RpcError::SubscriptionsUnsupported))
+        ));
+
+        // Secure URLs are required unless explicitly allowed otherwise:
+        assert!(matches!(
+            RpcClient::from_http_url("http://example.com"),
+            Err(Error::Rpc(RpcError::InsecureUrl(_)))
+        ));
+    }
+}
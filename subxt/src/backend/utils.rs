@@ -1,9 +1,14 @@
 //! RPC utils.
 
 use super::{StreamOf, StreamOfResults};
-use crate::error::Error;
+use crate::config::Header;
+use crate::error::{Error, RpcError};
 use futures::future::BoxFuture;
-use futures::{FutureExt, Stream, StreamExt};
+use futures::{FutureExt, Stream, StreamExt, TryStreamExt};
+use std::collections::{HashMap, VecDeque};
+use std::ops::RangeInclusive;
+use std::sync::{Arc, Mutex};
+use std::task::{Wake, Waker};
 use std::{future::Future, pin::Pin, task::Poll};
 
 /// Resubscribe callback.
@@ -176,6 +181,299 @@ where
     })))
 }
 
+/// A stream which shares a single underlying stream of results between any number of
+/// subscribers. Cloning a [`SharedStream`] creates a new subscriber, which receives every
+/// item produced from that point onwards; items produced before it was created are not
+/// replayed. The underlying stream is kept alive for as long as at least one subscriber
+/// exists, and is driven by whichever subscribers are being polled.
+///
+/// Items that a subscriber has not yet consumed are buffered for it, up to a capacity
+/// ([`SharedStream::DEFAULT_CAPACITY`] unless given via [`SharedStream::with_capacity`]).
+/// If a subscriber falls further behind than this, its oldest buffered items are dropped,
+/// and the next thing it receives is an [`Error::Other`] saying how many items it missed,
+/// after which it carries on with the items still buffered for it.
+///
+/// Errors are handed to every subscriber. The subscriber which polled the error receives
+/// the original. The others receive a copy: [`RpcError::DisconnectedWillReconnect`] and
+/// [`RpcError::SubscriptionDropped`] are copied as they are, and any other error is
+/// flattened into an [`Error::Other`] holding its message, since [`Error`] is not `Clone`.
+///
+/// # Example
+///
+/// ```no_run
+/// use subxt::backend::{utils::SharedStream, StreamOf};
+/// use futures::StreamExt;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let stream = StreamOf::new(Box::pin(futures::stream::iter([Ok(1), Ok(2)])));
+///     let mut first = SharedStream::new(stream);
+///     let mut second = first.clone();
+///
+///     assert_eq!(first.next().await.unwrap().unwrap(), 1);
+///     assert_eq!(second.next().await.unwrap().unwrap(), 1);
+/// }
+/// ```
+pub struct SharedStream<T> {
+    id: usize,
+    state: Arc<Mutex<SharedStreamState<T>>>,
+    wakers: Arc<SharedStreamWakers>,
+}
+
+struct SharedStreamState<T> {
+    stream: Option<StreamOfResults<T>>,
+    next_id: usize,
+    capacity: usize,
+    items: HashMap<usize, SubscriberItems<T>>,
+}
+
+/// The items buffered for a single subscriber, and how many were dropped since
+/// it last polled because it fell too far behind.
+struct SubscriberItems<T> {
+    items: VecDeque<Result<T, Error>>,
+    lagged: usize,
+}
+
+impl<T> SubscriberItems<T> {
+    fn new() -> Self {
+        SubscriberItems {
+            items: VecDeque::new(),
+            lagged: 0,
+        }
+    }
+
+    fn push(&mut self, item: Result<T, Error>, capacity: usize) {
+        if self.items.len() >= capacity {
+            self.items.pop_front();
+            self.lagged += 1;
+        }
+        self.items.push_back(item);
+    }
+
+    fn pop(&mut self) -> Option<Result<T, Error>> {
+        if self.lagged > 0 {
+            let lagged = std::mem::take(&mut self.lagged);
+            return Some(Err(Error::Other(format!(
+                "Shared stream subscriber fell behind and missed {lagged} items"
+            ))));
+        }
+        self.items.pop_front()
+    }
+}
+
+/// The wakers of any subscribers waiting on the underlying stream. This is what
+/// the underlying stream is polled with, so that it doesn't matter which of the
+/// subscribers happened to poll it last.
+#[derive(Default)]
+struct SharedStreamWakers(Mutex<HashMap<usize, Waker>>);
+
+impl Wake for SharedStreamWakers {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref()
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        let wakers = std::mem::take(&mut *self.0.lock().unwrap());
+        for waker in wakers.into_values() {
+            waker.wake();
+        }
+    }
+}
+
+impl<T: Clone> SharedStream<T> {
+    /// The number of items buffered for each subscriber when using [`SharedStream::new`].
+    pub const DEFAULT_CAPACITY: usize = 1024;
+
+    /// Share the given stream. The returned [`SharedStream`] is the first subscriber;
+    /// clone it to create more.
+    pub fn new(stream: StreamOfResults<T>) -> Self {
+        Self::with_capacity(stream, Self::DEFAULT_CAPACITY)
+    }
+
+    /// Share the given stream, buffering at most `capacity` items for each subscriber
+    /// before dropping the oldest of them. A `capacity` of 0 is treated as 1.
+    pub fn with_capacity(stream: StreamOfResults<T>, capacity: usize) -> Self {
+        let mut items = HashMap::new();
+        items.insert(0, SubscriberItems::new());
+
+        SharedStream {
+            id: 0,
+            state: Arc::new(Mutex::new(SharedStreamState {
+                stream: Some(stream),
+                next_id: 1,
+                capacity: capacity.max(1),
+                items,
+            })),
+            wakers: Arc::new(SharedStreamWakers::default()),
+        }
+    }
+}
+
+impl<T> Clone for SharedStream<T> {
+    fn clone(&self) -> Self {
+        let mut state = self.state.lock().unwrap();
+        let id = state.next_id;
+        state.next_id += 1;
+        state.items.insert(id, SubscriberItems::new());
+
+        SharedStream {
+            id,
+            state: self.state.clone(),
+            wakers: self.wakers.clone(),
+        }
+    }
+}
+
+impl<T> Drop for SharedStream<T> {
+    fn drop(&mut self) {
+        self.state.lock().unwrap().items.remove(&self.id);
+        self.wakers.0.lock().unwrap().remove(&self.id);
+    }
+}
+
+impl<T> std::fmt::Debug for SharedStream<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedStream")
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+impl<T: Clone> Stream for SharedStream<T> {
+    type Item = Result<T, Error>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let mut state = self.state.lock().unwrap();
+        let state = &mut *state;
+
+        if let Some(item) = state.items.get_mut(&self.id).and_then(|i| i.pop()) {
+            return Poll::Ready(Some(item));
+        }
+
+        let Some(stream) = state.stream.as_mut() else {
+            return Poll::Ready(None);
+        };
+
+        // Register interest before polling, so that we're woken whichever
+        // subscriber the underlying stream was last polled by.
+        self.wakers
+            .0
+            .lock()
+            .unwrap()
+            .insert(self.id, cx.waker().clone());
+        let waker = Waker::from(self.wakers.clone());
+
+        match stream.poll_next_unpin(&mut std::task::Context::from_waker(&waker)) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(None) => {
+                state.stream = None;
+                self.wakers.wake_by_ref();
+                Poll::Ready(None)
+            }
+            Poll::Ready(Some(item)) => {
+                for (id, items) in state.items.iter_mut() {
+                    if *id == self.id {
+                        continue;
+                    }
+                    let item = match &item {
+                        Ok(val) => Ok(val.clone()),
+                        Err(err) => Err(copy_error(err)),
+                    };
+                    items.push(item, state.capacity);
+                }
+                self.wakers.0.lock().unwrap().remove(&self.id);
+                self.wakers.wake_by_ref();
+                Poll::Ready(Some(item))
+            }
+        }
+    }
+}
+
+/// A header handed back from [`throttle_every`], along with the range of block numbers
+/// which were skipped over since the previous header was handed back.
+#[derive(Debug, Clone)]
+pub struct ThrottledHeader<H> {
+    /// The header of a block whose number is a multiple of the throttling interval.
+    pub header: H,
+    /// The numbers of the blocks seen and skipped since the previous header was handed
+    /// back (or since the stream started), if any were.
+    pub skipped: Option<RangeInclusive<u64>>,
+}
+
+/// Take a stream of block headers and hand back only those whose block number is a
+/// multiple of `n`, along with the range of block numbers skipped in between. An `n`
+/// of 0 is treated as 1. Errors are passed through as they are.
+///
+/// Since headers are picked by block number and not by how many have been seen, the
+/// same blocks are handed back regardless of where the stream starts, which keeps the
+/// output consistent across restarts. The given stream is expected to hand back headers
+/// in increasing order and without gaps, as
+/// [`crate::backend::legacy::LegacyRpcMethods::chain_subscribe_finalized_heads_from()`]
+/// does; any block missing from it will not be handed back even if its number is a
+/// multiple of `n`.
+///
+/// # Example
+///
+/// ```no_run
+/// use subxt::backend::{legacy::LegacyRpcMethods, rpc::RpcClient, utils::throttle_every};
+/// use subxt::PolkadotConfig;
+/// use futures::StreamExt;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let rpc_client = RpcClient::from_url("ws://127.0.0.1:9944").await.unwrap();
+///     let rpc = LegacyRpcMethods::<PolkadotConfig>::new(rpc_client);
+///
+///     let genesis_hash = rpc.genesis_hash().await.unwrap();
+///     let headers = rpc.chain_subscribe_finalized_heads_from(genesis_hash).await.unwrap();
+///     let mut throttled = throttle_every(headers, 100);
+///     while let Some(throttled) = throttled.next().await {
+///         let throttled = throttled.unwrap();
+///         println!("Block #{}, skipped {:?}", throttled.header.number, throttled.skipped);
+///     }
+/// }
+/// ```
+pub fn throttle_every<H, S>(
+    headers: S,
+    n: u32,
+) -> impl Stream<Item = Result<ThrottledHeader<H>, Error>> + Send
+where
+    H: Header + Send,
+    S: Stream<Item = Result<H, Error>> + Send,
+{
+    let n = u64::from(n.max(1));
+    let mut first_skipped = None;
+    headers.try_filter_map(move |header| {
+        let number: u64 = header.number().into();
+        let throttled = if number % n == 0 {
+            let skipped = first_skipped
+                .take()
+                .map(|first| first..=number.saturating_sub(1))
+                .filter(|skipped| !skipped.is_empty());
+            Some(ThrottledHeader { header, skipped })
+        } else {
+            first_skipped.get_or_insert(number);
+            None
+        };
+        futures::future::ready(Ok(throttled))
+    })
+}
+
+/// [`Error`] is not `Clone`, so this produces a copy of an error to hand to
+/// other subscribers of a [`SharedStream`].
+fn copy_error(err: &Error) -> Error {
+    match err {
+        Error::Rpc(RpcError::DisconnectedWillReconnect(msg)) => {
+            Error::Rpc(RpcError::DisconnectedWillReconnect(msg.clone()))
+        }
+        Error::Rpc(RpcError::SubscriptionDropped) => Error::Rpc(RpcError::SubscriptionDropped),
+        err => Error::Other(err.to_string()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -268,4 +566,115 @@ mod tests {
         assert!(matches!(result[1], Err(ref e) if e.is_disconnected_will_reconnect()));
         assert!(matches!(result[2], Err(ref e) if matches!(e, Error::Other(_))));
     }
+
+    #[tokio::test]
+    async fn shared_stream_fans_out_without_replay() {
+        let (tx, rx) = futures::channel::mpsc::unbounded::<Result<usize, Error>>();
+        let mut first = SharedStream::new(StreamOf::new(Box::pin(rx)));
+        let second = first.clone();
+
+        tx.unbounded_send(Ok(1)).unwrap();
+        assert!(matches!(first.next().await, Some(Ok(1))));
+
+        // Subscribers created later only see items from then on.
+        let third = first.clone();
+        tx.unbounded_send(Ok(2)).unwrap();
+        tx.unbounded_send(Err(disconnect_err())).unwrap();
+        tx.unbounded_send(Err(custom_err())).unwrap();
+        drop(tx);
+
+        let first: Vec<_> = first.collect().await;
+        let second: Vec<_> = second.collect().await;
+        let third: Vec<_> = third.collect().await;
+
+        assert!(matches!(first[..], [Ok(2), Err(_), Err(_)]));
+        assert!(matches!(second[..], [Ok(1), Ok(2), Err(_), Err(_)]));
+        assert!(matches!(third[..], [Ok(2), Err(_), Err(_)]));
+
+        // Copies of errors given to other subscribers keep their reconnect semantics.
+        for results in [first, second, third] {
+            let [.., Err(disconnected), Err(other)] = &results[..] else {
+                panic!("expected two errors");
+            };
+            assert!(disconnected.is_disconnected_will_reconnect());
+            assert!(matches!(other, Error::Other(_)));
+        }
+    }
+
+    #[tokio::test]
+    async fn shared_stream_reports_lagging_subscribers() {
+        let (tx, rx) = futures::channel::mpsc::unbounded::<Result<usize, Error>>();
+        let mut first = SharedStream::with_capacity(StreamOf::new(Box::pin(rx)), 2);
+        let mut second = first.clone();
+
+        for n in 1..=5 {
+            tx.unbounded_send(Ok(n)).unwrap();
+            assert!(matches!(first.next().await, Some(Ok(r)) if r == n));
+        }
+
+        // The subscriber which fell behind is told how many items it missed, and
+        // then carries on with the most recent ones.
+        assert!(
+            matches!(second.next().await, Some(Err(Error::Other(msg))) if msg.contains("missed 3 items"))
+        );
+        assert!(matches!(second.next().await, Some(Ok(4))));
+        assert!(matches!(second.next().await, Some(Ok(5))));
+
+        drop(tx);
+        assert!(first.next().await.is_none());
+        assert!(second.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn shared_stream_wakes_waiting_subscribers() {
+        let (tx, rx) = futures::channel::mpsc::unbounded::<Result<usize, Error>>();
+        let first = SharedStream::new(StreamOf::new(Box::pin(rx)));
+        let second = first.clone();
+
+        // Both subscribers wait on the underlying stream at the same time.
+        let first = tokio::spawn(first.take(2).collect::<Vec<_>>());
+        let second = tokio::spawn(second.take(2).collect::<Vec<_>>());
+        tokio::task::yield_now().await;
+
+        tx.unbounded_send(Ok(1)).unwrap();
+        tx.unbounded_send(Ok(2)).unwrap();
+
+        for results in [first.await.unwrap(), second.await.unwrap()] {
+            assert!(matches!(results[..], [Ok(1), Ok(2)]));
+        }
+    }
+
+    #[tokio::test]
+    async fn shared_stream_closes_once_all_subscribers_dropped() {
+        let (tx, rx) = futures::channel::mpsc::unbounded::<Result<usize, Error>>();
+        let first = SharedStream::new(StreamOf::new(Box::pin(rx)));
+        let second = first.clone();
+
+        drop(first);
+        assert!(!tx.is_closed());
+        drop(second);
+        assert!(tx.is_closed());
+    }
+
+    #[tokio::test]
+    async fn throttle_every_picks_headers_by_block_number() {
+        use crate::config::substrate::{BlakeTwo256, SubstrateHeader};
+
+        let header = |number| SubstrateHeader::<u32, BlakeTwo256> {
+            parent_hash: Default::default(),
+            number,
+            state_root: Default::default(),
+            extrinsics_root: Default::default(),
+            digest: Default::default(),
+        };
+        let headers = futures::stream::iter((3..=12).map(header).map(Ok));
+
+        let throttled: Vec<(u32, Option<RangeInclusive<u64>>)> = throttle_every(headers, 5)
+            .map_ok(|t| (t.header.number, t.skipped))
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(throttled, vec![(5, Some(3..=4)), (10, Some(6..=9))]);
+    }
 }
@@ -27,6 +27,15 @@ pub struct Block<T: Config, C> {
     cached_events: CachedEvents<T>,
 }
 
+impl<T: Config, C> std::fmt::Debug for Block<T, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Block")
+            .field("hash", &self.block_ref.hash())
+            .field("header", &self.header)
+            .finish()
+    }
+}
+
 // A cache for our events so we don't fetch them more than once when
 // iterating over events for extrinsics.
 pub(crate) type CachedEvents<T> = Arc<AsyncMutex<Option<events::Events<T>>>>;
@@ -10,8 +10,10 @@ use crate::{
     error::{BlockError, Error},
     utils::PhantomDataSendSync,
 };
+use codec::{Decode, Encode};
 use derive_where::derive_where;
 use futures::StreamExt;
+use std::collections::VecDeque;
 use std::future::Future;
 
 type BlockStream<T> = StreamOfResults<T>;
@@ -130,6 +132,109 @@ where
             BlockStreamRes::Ok(stream)
         })
     }
+
+    /// Subscribe to all new blocks imported by the node onto the current best fork, as per
+    /// [`Self::subscribe_best()`], but also hand back a [`BestBlockUpdate::Reorg`] whenever
+    /// the best fork changes so that anything derived from retracted blocks (such as their
+    /// events) can be rolled back. A block is never handed back twice while it remains on
+    /// the best fork, even if the node reports it as the best block again.
+    ///
+    /// Reorgs are detected by comparing the parent hash of each new block with the blocks
+    /// recently handed back, and so only reorgs of up to 256 blocks can be detected.
+    pub fn subscribe_best_with_reorgs(
+        &self,
+    ) -> impl Future<Output = Result<BlockStream<BestBlockUpdate<T, Client>>, Error>> + Send + 'static
+    where
+        Client: Send + Sync + 'static,
+    {
+        let sub = self.subscribe_best();
+        async move {
+            let mut recent_blocks = RecentBlocks::new(256);
+            let sub = sub.await?.flat_map(move |block| {
+                let updates = match block.map(|b| (parent_hash::<T>(b.header()), b)) {
+                    Ok((Ok(parent_hash), block)) => {
+                        let (retracted, is_new) =
+                            recent_blocks.push(block.number().into(), block.hash(), parent_hash);
+
+                        let reorg = (!retracted.is_empty())
+                            .then(|| Ok(BestBlockUpdate::Reorg { retracted }));
+                        let block = is_new.then(|| Ok(BestBlockUpdate::Block(block)));
+                        reorg.into_iter().chain(block).collect()
+                    }
+                    Ok((Err(e), _)) | Err(e) => vec![Err(e)],
+                };
+                futures::stream::iter(updates)
+            });
+            BlockStreamRes::Ok(StreamOfResults::new(Box::pin(sub)))
+        }
+    }
+}
+
+/// An update handed back from [`BlocksClient::subscribe_best_with_reorgs()`].
+#[derive_where(Debug)]
+pub enum BestBlockUpdate<T: Config, C> {
+    /// A new block on the best fork.
+    Block(Block<T, C>),
+    /// The best fork changed, and these previously handed back blocks are no longer on it.
+    /// The retracted block hashes are ordered from the highest block to the lowest.
+    Reorg {
+        /// The hashes of the retracted blocks.
+        retracted: Vec<T::Hash>,
+    },
+}
+
+/// Every header begins with the hash of its parent, and so we decode it from the
+/// encoded header rather than asking [`crate::config::Header`] implementations for it.
+fn parent_hash<T: Config>(header: &T::Header) -> Result<T::Hash, Error> {
+    Ok(T::Hash::decode(&mut &*header.encode())?)
+}
+
+/// Keeps track of the most recent blocks handed back on the best fork, to work out which
+/// of them are retracted when a new best block arrives.
+struct RecentBlocks<Hash> {
+    max_len: usize,
+    blocks: VecDeque<(u64, Hash)>,
+}
+
+impl<Hash: Copy + PartialEq> RecentBlocks<Hash> {
+    fn new(max_len: usize) -> Self {
+        RecentBlocks {
+            max_len,
+            blocks: VecDeque::new(),
+        }
+    }
+
+    /// Add a new best block, returning the hashes of any blocks that it retracts (highest
+    /// first) and whether it is a block that we haven't already handed back.
+    fn push(&mut self, number: u64, hash: Hash, parent_hash: Hash) -> (Vec<Hash>, bool) {
+        let mut retracted = Vec::new();
+
+        // If we've seen this block already, the best fork has gone back to it, and so any
+        // blocks that we've seen after it are retracted.
+        if let Some(idx) = self.blocks.iter().position(|(_, h)| *h == hash) {
+            while self.blocks.len() > idx + 1 {
+                retracted.extend(self.blocks.pop_back().map(|(_, h)| h));
+            }
+            return (retracted, false);
+        }
+
+        // Otherwise, retract anything that's not an ancestor of the new block.
+        let knows_parent = self.blocks.iter().any(|(_, h)| *h == parent_hash);
+        while let Some((last_number, last_hash)) = self.blocks.back().copied() {
+            let is_parent = last_hash == parent_hash;
+            if is_parent || (last_number < number && !knows_parent) {
+                break;
+            }
+            self.blocks.pop_back();
+            retracted.push(last_hash);
+        }
+
+        self.blocks.push_back((number, hash));
+        if self.blocks.len() > self.max_len {
+            self.blocks.pop_front();
+        }
+        (retracted, true)
+    }
 }
 
 /// Take a promise that will return a subscription to some block headers,
@@ -156,3 +261,116 @@ where
     });
     BlockStreamRes::Ok(StreamOfResults::new(Box::pin(sub)))
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::backend::{
+        rpc::RawRpcSubscription,
+        test::{mock_online_client, rpc_client::MockRpcBuilder},
+    };
+    use crate::config::substrate::{BlakeTwo256, SubstrateHeader};
+    use crate::config::Header;
+    use crate::utils::H256;
+    use serde_json::value::RawValue;
+
+    #[tokio::test]
+    async fn subscribe_best_with_reorgs_reports_retracted_blocks() {
+        let header =
+            |number: u32, parent_hash: H256, fork: u64| SubstrateHeader::<u32, BlakeTwo256> {
+                parent_hash,
+                number,
+                // Tell apart blocks at the same height on different forks:
+                state_root: H256::from_low_u64_le(fork),
+                extrinsics_root: H256::zero(),
+                digest: Default::default(),
+            };
+        let a = header(1, H256::zero(), 0);
+        let b = header(2, a.hash(), 0);
+        let c = header(3, b.hash(), 0);
+        let c2 = header(3, b.hash(), 1);
+        let x = header(4, a.hash(), 1);
+        let (a_hash, b_hash, c_hash, c2_hash, x_hash) =
+            (a.hash(), b.hash(), c.hash(), c2.hash(), x.hash());
+
+        // The node reports `b` twice, then switches to `c2` and then to a fork from `a`.
+        let headers = [a, b.clone(), b, c, c2, x];
+        let rpc_client = MockRpcBuilder::default()
+            .add_subscription("chain_subscribeNewHeads", move |_, _, _| {
+                let headers = headers.clone().map(|h| {
+                    Ok(RawValue::from_string(serde_json::to_string(&h).unwrap()).unwrap())
+                });
+                Box::pin(async move {
+                    Ok(RawRpcSubscription {
+                        stream: futures::stream::iter(headers).boxed(),
+                        id: Some("ID".to_string()),
+                    })
+                })
+            })
+            .build();
+
+        let client = mock_online_client(rpc_client);
+
+        let updates: Vec<_> = client
+            .blocks()
+            .subscribe_best_with_reorgs()
+            .await
+            .unwrap()
+            .take(7)
+            .map(|update| match update.unwrap() {
+                BestBlockUpdate::Block(block) => (Some(block.hash()), vec![]),
+                BestBlockUpdate::Reorg { retracted } => (None, retracted),
+            })
+            .collect()
+            .await;
+
+        assert_eq!(
+            updates,
+            vec![
+                (Some(a_hash), vec![]),
+                (Some(b_hash), vec![]),
+                (Some(c_hash), vec![]),
+                (None, vec![c_hash]),
+                (Some(c2_hash), vec![]),
+                (None, vec![c2_hash, b_hash]),
+                (Some(x_hash), vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn recent_blocks_on_one_fork() {
+        let mut blocks = RecentBlocks::new(256);
+        assert_eq!(blocks.push(1, 'a', '0'), (vec![], true));
+        assert_eq!(blocks.push(2, 'b', 'a'), (vec![], true));
+        // Blocks can be skipped over, and the parent may not be known:
+        assert_eq!(blocks.push(4, 'd', 'c'), (vec![], true));
+        // The same block again is not handed back twice:
+        assert_eq!(blocks.push(4, 'd', 'c'), (vec![], false));
+    }
+
+    #[test]
+    fn recent_blocks_reorgs() {
+        let mut blocks = RecentBlocks::new(256);
+        blocks.push(1, 'a', '0');
+        blocks.push(2, 'b', 'a');
+        blocks.push(3, 'c', 'b');
+
+        // A new block at the same height retracts the old one:
+        assert_eq!(blocks.push(3, 'C', 'b'), (vec!['c'], true));
+        // A higher block on a different fork retracts everything after its parent:
+        assert_eq!(blocks.push(4, 'X', 'a'), (vec!['C', 'b'], true));
+        // Going back to a block already seen retracts the blocks after it:
+        assert_eq!(blocks.push(1, 'a', '0'), (vec!['X'], false));
+    }
+
+    #[test]
+    fn recent_blocks_are_bounded() {
+        let mut blocks = RecentBlocks::new(2);
+        blocks.push(1, 'a', '0');
+        blocks.push(2, 'b', 'a');
+        blocks.push(3, 'c', 'b');
+        // 'a' is no longer remembered, so is handed back again:
+        assert_eq!(blocks.push(1, 'a', '0'), (vec!['c', 'b'], true));
+    }
+}
@@ -6,8 +6,9 @@ use crate::{
     blocks::block_types::{get_events, CachedEvents},
     client::{OfflineClientT, OnlineClientT},
     config::{Config, Hasher},
-    error::Error,
+    error::{DispatchError, Error},
     events,
+    utils::DispatchInfo,
 };
 
 use derive_where::derive_where;
@@ -186,11 +187,27 @@ where
         self.inner.address_bytes()
     }
 
+    /// See [`subxt_core::blocks::ExtrinsicDetails::address()`].
+    pub fn address(&self) -> Option<Result<T::Address, Error>>
+    where
+        T::Address: codec::Decode,
+    {
+        Some(self.inner.address()?.map_err(Into::into))
+    }
+
     /// See [`subxt_core::blocks::ExtrinsicDetails::signature_bytes()`].
     pub fn signature_bytes(&self) -> Option<&[u8]> {
         self.inner.signature_bytes()
     }
 
+    /// See [`subxt_core::blocks::ExtrinsicDetails::signature()`].
+    pub fn signature(&self) -> Option<Result<T::Signature, Error>>
+    where
+        T::Signature: codec::Decode,
+    {
+        Some(self.inner.signature()?.map_err(Into::into))
+    }
+
     /// See [`subxt_core::blocks::ExtrinsicDetails::signed_extensions_bytes()`].
     pub fn signed_extensions_bytes(&self) -> Option<&[u8]> {
         self.inner.signed_extensions_bytes()
@@ -345,6 +362,53 @@ impl<T: Config> ExtrinsicEvents<T> {
         self.find::<Ev>().last().transpose()
     }
 
+    /// Find the `System.ExtrinsicSuccess` or `System.ExtrinsicFailed` event emitted for
+    /// this extrinsic, and hand back the [`DispatchInfo`] that it reports (ie the weight
+    /// that the extrinsic actually consumed) along with whether it succeeded. Returns
+    /// `None` if neither event was emitted. Weights reported by older runtimes as a single
+    /// `u64` are normalized into a [`crate::utils::Weight`] with a `proof_size` of 0.
+    pub fn outcome(&self) -> Result<Option<ExtrinsicOutcome>, Error> {
+        // Both events have a `dispatch_info` field, which is all that we decode from them
+        // here; the dispatch error in `ExtrinsicFailed` needs decoding separately.
+        #[derive(scale_decode::DecodeAsType)]
+        struct ExtrinsicSuccess {
+            dispatch_info: DispatchInfo,
+        }
+        impl events::StaticEvent for ExtrinsicSuccess {
+            const PALLET: &'static str = "System";
+            const EVENT: &'static str = "ExtrinsicSuccess";
+        }
+
+        #[derive(scale_decode::DecodeAsType)]
+        struct ExtrinsicFailed {
+            dispatch_info: DispatchInfo,
+        }
+        impl events::StaticEvent for ExtrinsicFailed {
+            const PALLET: &'static str = "System";
+            const EVENT: &'static str = "ExtrinsicFailed";
+        }
+
+        for ev in self.iter() {
+            let ev = ev?;
+            if let Some(ExtrinsicSuccess { dispatch_info }) = ev.as_event()? {
+                return Ok(Some(ExtrinsicOutcome {
+                    dispatch_info,
+                    result: Ok(()),
+                }));
+            }
+            if let Some(ExtrinsicFailed { dispatch_info }) = ev.as_event()? {
+                let dispatch_error =
+                    DispatchError::decode_from(ev.field_bytes(), self.events.metadata().clone())?;
+                return Ok(Some(ExtrinsicOutcome {
+                    dispatch_info,
+                    result: Err(dispatch_error),
+                }));
+            }
+        }
+
+        Ok(None)
+    }
+
     /// Find an event in those associated with this transaction. Returns true if it was found.
     ///
     /// This works in the same way that [`events::Events::has()`] does, with the
@@ -353,3 +417,120 @@ impl<T: Config> ExtrinsicEvents<T> {
         Ok(self.find::<Ev>().next().transpose()?.is_some())
     }
 }
+
+/// The outcome of dispatching an extrinsic, as returned from [`ExtrinsicEvents::outcome()`].
+#[derive(Debug)]
+pub struct ExtrinsicOutcome {
+    /// Information about the dispatch of the extrinsic, including the weight it consumed.
+    pub dispatch_info: DispatchInfo,
+    /// Whether the extrinsic succeeded, or the error that it failed with.
+    pub result: Result<(), DispatchError>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        error::DispatchError,
+        events::{Events, Phase},
+        utils::{DispatchClass, Pays, Weight, H256},
+        Metadata, PolkadotConfig,
+    };
+    use codec::{Compact, Decode, Encode};
+    use frame_metadata::{RuntimeMetadata, RuntimeMetadataPrefixed};
+    use scale_info::{TypeDef, TypeDefPrimitive};
+
+    // Load the metadata, optionally rewriting the weight reported in the system events into
+    // the older single `u64` shape.
+    fn metadata(u64_weights: bool) -> Metadata {
+        let metadata_bytes = include_bytes!("../../../artifacts/polkadot_metadata_small.scale");
+        let mut prefixed = RuntimeMetadataPrefixed::decode(&mut &metadata_bytes[..]).unwrap();
+        let metadata = Metadata::decode(&mut &metadata_bytes[..]).unwrap();
+        if !u64_weights {
+            return metadata;
+        }
+
+        // Find the type of the weight in the `dispatch_info` of `ExtrinsicSuccess`:
+        let success = metadata
+            .pallet_by_name("System")
+            .and_then(|p| p.event_variant_by_name("ExtrinsicSuccess"))
+            .unwrap();
+        let dispatch_info = metadata.types().resolve(success.fields[0].ty.id).unwrap();
+        let TypeDef::Composite(dispatch_info) = &dispatch_info.type_def else {
+            panic!("expected dispatch_info to be a composite");
+        };
+        let weight_id = dispatch_info.fields[0].ty.id;
+
+        let RuntimeMetadata::V15(v15) = &mut prefixed.1 else {
+            panic!("expected V15 metadata");
+        };
+        let weight = v15
+            .types
+            .types
+            .iter_mut()
+            .find(|ty| ty.id == weight_id)
+            .unwrap();
+        weight.ty.type_def = TypeDef::Primitive(TypeDefPrimitive::U64);
+
+        subxt_metadata::Metadata::try_from(prefixed).unwrap().into()
+    }
+
+    // Build the events for a block in which extrinsic 0 emits the given system event.
+    fn extrinsic_events(
+        metadata: Metadata,
+        event_name: &str,
+        fields: Vec<u8>,
+    ) -> ExtrinsicEvents<PolkadotConfig> {
+        let pallet = metadata.pallet_by_name("System").unwrap();
+        let variant = pallet.event_variant_by_name(event_name).unwrap();
+
+        let mut bytes = Compact(1u32).encode();
+        bytes.extend(Phase::ApplyExtrinsic(0).encode());
+        bytes.extend([pallet.index(), variant.index]);
+        bytes.extend(fields);
+        bytes.extend(Compact(0u32).encode());
+
+        ExtrinsicEvents::new(H256::zero(), 0, Events::decode_from(bytes, metadata))
+    }
+
+    #[test]
+    fn outcome_decodes_both_weight_shapes() {
+        // DispatchClass::Operational and Pays::No.
+        let class_and_pays = [1u8, 1u8];
+        let expected_info = |proof_size| DispatchInfo {
+            weight: Weight::from_parts(10, proof_size),
+            class: DispatchClass::Operational,
+            pays_fee: Pays::No,
+        };
+        // DispatchError::BadOrigin.
+        let bad_origin = [2u8];
+
+        for u64_weights in [false, true] {
+            let weight = if u64_weights {
+                10u64.encode()
+            } else {
+                (Compact(10u64), Compact(20u64)).encode()
+            };
+            let proof_size = if u64_weights { 0 } else { 20 };
+            let dispatch_info = [weight, class_and_pays.to_vec()].concat();
+
+            let events = extrinsic_events(
+                metadata(u64_weights),
+                "ExtrinsicSuccess",
+                dispatch_info.clone(),
+            );
+            let outcome = events.outcome().unwrap().unwrap();
+            assert_eq!(outcome.dispatch_info, expected_info(proof_size));
+            assert!(outcome.result.is_ok());
+
+            let events = extrinsic_events(
+                metadata(u64_weights),
+                "ExtrinsicFailed",
+                [bad_origin.to_vec(), dispatch_info].concat(),
+            );
+            let outcome = events.outcome().unwrap().unwrap();
+            assert_eq!(outcome.dispatch_info, expected_info(proof_size));
+            assert!(matches!(outcome.result, Err(DispatchError::BadOrigin)));
+        }
+    }
+}
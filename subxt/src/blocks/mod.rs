@@ -12,10 +12,10 @@ mod extrinsic_types;
 pub use crate::backend::BlockRef;
 
 pub use block_types::Block;
-pub use blocks_client::BlocksClient;
+pub use blocks_client::{BestBlockUpdate, BlocksClient};
 pub use extrinsic_types::{
-    ExtrinsicDetails, ExtrinsicEvents, ExtrinsicSignedExtension, ExtrinsicSignedExtensions,
-    Extrinsics, FoundExtrinsic, StaticExtrinsic,
+    ExtrinsicDetails, ExtrinsicEvents, ExtrinsicOutcome, ExtrinsicSignedExtension,
+    ExtrinsicSignedExtensions, Extrinsics, FoundExtrinsic, StaticExtrinsic,
 };
 
 // We get account nonce info in tx_client, too, so re-use the logic:
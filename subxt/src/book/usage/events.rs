@@ -39,6 +39,11 @@
 //! methods like [`crate::events::Events::iter()`] and [`crate::events::Events::find()`]. See
 //! [`crate::events::Events`] and [`crate::events::EventDetails`] for more information.
 //!
+//! To react to events as new blocks are finalized, use [`crate::events::EventsClient::subscribe_finalized()`].
+//! This hands back an [`crate::events::EventSubscription`], which can be narrowed down to the events of a
+//! single type via [`crate::events::EventSubscription::filter()`], or of a single pallet via
+//! [`crate::events::EventSubscription::filter_pallet()`], across all of the blocks.
+//!
 //! ## Example
 //!
 //! Here's an example which puts this all together:
@@ -3,6 +3,7 @@
 // see LICENSE for license details.
 
 use crate::custom_values::CustomValuesClient;
+use crate::error::MetadataError;
 use crate::{
     blocks::BlocksClient, constants::ConstantsClient, events::EventsClient,
     runtime_api::RuntimeApiClient, storage::StorageClient, tx::TxClient, Config, Metadata,
@@ -33,6 +34,13 @@ pub trait OfflineClientT<T: Config>: Clone + Send + Sync + 'static {
         }
     }
 
+    /// Check that the [`Metadata`] used in this client is compatible with some `expected`
+    /// metadata, for instance the metadata that statically generated code was built from.
+    /// See [`subxt_core::metadata::validate`] for more details.
+    fn validate_metadata(&self, expected: &Metadata) -> Result<(), MetadataError> {
+        subxt_core::metadata::validate(&self.metadata(), expected)
+    }
+
     /// Work with transactions.
     fn tx(&self) -> TxClient<T, Self> {
         TxClient::new(self.clone())
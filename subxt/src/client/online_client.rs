@@ -17,6 +17,7 @@ use crate::{
 };
 use derive_where::derive_where;
 use futures::future;
+use std::collections::VecDeque;
 use std::sync::{Arc, RwLock};
 use subxt_core::client::{ClientState, RuntimeVersion};
 
@@ -40,6 +41,7 @@ struct Inner<T: Config> {
     genesis_hash: T::Hash,
     runtime_version: RuntimeVersion,
     metadata: Metadata,
+    historic_metadata: HistoricMetadata,
 }
 
 impl<T: Config> std::fmt::Debug for OnlineClient<T> {
@@ -151,6 +153,7 @@ impl<T: Config> OnlineClient<T> {
                 genesis_hash,
                 runtime_version,
                 metadata: metadata.into(),
+                historic_metadata: HistoricMetadata::default(),
             })),
             backend,
         })
@@ -263,6 +266,41 @@ impl<T: Config> OnlineClient<T> {
         inner.metadata = metadata.into();
     }
 
+    /// Return the [`Metadata`] for the runtime that was in use at the given block. This is
+    /// useful for decoding details from older blocks which were produced prior to a runtime
+    /// upgrade.
+    ///
+    /// If the block was produced by the same runtime as the client is currently using, then
+    /// this is the same as [`OnlineClient::metadata()`]. Otherwise, the metadata is fetched
+    /// from the node and cached by spec version, so that other blocks produced by the same
+    /// runtime don't need to fetch it again. Only the most recently used metadata for a
+    /// handful of runtimes is kept around.
+    pub async fn metadata_at(&self, block_hash: T::Hash) -> Result<Metadata, Error> {
+        // `Core_version` hands back a SCALE encoded `sp_version::RuntimeVersion`, which begins
+        // with the spec name, impl name, authoring version and then the spec version.
+        let (_spec_name, _impl_name, _authoring_version, spec_version): (String, String, u32, u32) =
+            self.backend
+                .call_decoding("Core_version", None, block_hash)
+                .await?;
+
+        {
+            let mut inner = self.inner.write().expect("shouldn't be poisoned");
+            if inner.runtime_version.spec_version == spec_version {
+                return Ok(inner.metadata.clone());
+            }
+            if let Some(metadata) = inner.historic_metadata.get(spec_version) {
+                return Ok(metadata);
+            }
+        }
+
+        let metadata = OnlineClient::fetch_metadata(&*self.backend, block_hash).await?;
+        let mut inner = self.inner.write().expect("shouldn't be poisoned");
+        inner
+            .historic_metadata
+            .insert(spec_version, metadata.clone());
+        Ok(metadata)
+    }
+
     /// Return the genesis hash.
     pub fn genesis_hash(&self) -> T::Hash {
         let inner = self.inner.read().expect("shouldn't be poisoned");
@@ -497,6 +535,35 @@ impl Update {
     }
 }
 
+/// A small cache of the metadata for runtimes other than the current one, keyed by spec
+/// version. The least recently used metadata is evicted once it's full.
+#[derive(Debug, Default)]
+struct HistoricMetadata {
+    // The most recently used entries are at the back.
+    entries: VecDeque<(u32, Metadata)>,
+}
+
+impl HistoricMetadata {
+    /// The maximum number of runtimes that we'll hold metadata for.
+    const MAX_ENTRIES: usize = 8;
+
+    fn get(&mut self, spec_version: u32) -> Option<Metadata> {
+        let idx = self.entries.iter().position(|(v, _)| *v == spec_version)?;
+        let entry = self.entries.remove(idx)?;
+        let metadata = entry.1.clone();
+        self.entries.push_back(entry);
+        Some(metadata)
+    }
+
+    fn insert(&mut self, spec_version: u32, metadata: Metadata) {
+        self.entries.retain(|(v, _)| *v != spec_version);
+        if self.entries.len() >= Self::MAX_ENTRIES {
+            self.entries.pop_front();
+        }
+        self.entries.push_back((spec_version, metadata));
+    }
+}
+
 /// Helper to wait until the runtime upgrade is applied on at finalized block.
 async fn wait_runtime_upgrade_in_finalized_block<T: Config>(
     client: &OnlineClient<T>,
@@ -552,3 +619,37 @@ async fn wait_runtime_upgrade_in_finalized_block<T: Config>(
 
     Some(Ok(block_ref))
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn metadata() -> Metadata {
+        let bytes = include_bytes!("../../../artifacts/polkadot_metadata_small.scale");
+        codec::Decode::decode(&mut &bytes[..]).unwrap()
+    }
+
+    #[test]
+    fn historic_metadata_evicts_least_recently_used() {
+        let metadata = metadata();
+        let mut cache = HistoricMetadata::default();
+
+        for spec_version in 0..HistoricMetadata::MAX_ENTRIES as u32 {
+            cache.insert(spec_version, metadata.clone());
+        }
+
+        // Using the oldest entry means that it's no longer the next to be evicted:
+        assert!(cache.get(0).is_some());
+        cache.insert(100, metadata.clone());
+
+        assert!(cache.get(1).is_none());
+        assert!(cache.get(0).is_some());
+        assert!(cache.get(100).is_some());
+        assert_eq!(cache.entries.len(), HistoricMetadata::MAX_ENTRIES);
+
+        // Re-inserting an existing entry doesn't evict anything:
+        cache.insert(100, metadata);
+        assert_eq!(cache.entries.len(), HistoricMetadata::MAX_ENTRIES);
+        assert!(cache.get(2).is_some());
+    }
+}
@@ -155,6 +155,9 @@ pub enum RpcError {
     /// The connection was lost and automatically reconnected.
     #[error("RPC error: the connection was lost `{0}`; reconnect automatically initiated")]
     DisconnectedWillReconnect(String),
+    /// The RPC client does not support subscriptions (for instance, because it communicates over HTTP).
+    #[error("RPC error: subscriptions are not supported by this RPC client")]
+    SubscriptionsUnsupported,
 }
 
 impl RpcError {
@@ -235,4 +238,12 @@ pub enum TransactionError {
     /// The transaction was dropped.
     #[error("The transaction was dropped: {0}")]
     Dropped(String),
+    /// The transaction succeeded, but did not emit the event that was expected.
+    #[error("The transaction did not emit a {pallet_name}.{event_name} event")]
+    EventNotFound {
+        /// The name of the pallet that the expected event belongs to.
+        pallet_name: String,
+        /// The name of the expected event.
+        event_name: String,
+    },
 }
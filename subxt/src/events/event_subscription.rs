@@ -0,0 +1,214 @@
+// Copyright 2019-2024 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use crate::{
+    backend::{StreamOf, StreamOfResults},
+    blocks::Block,
+    client::OnlineClientT,
+    error::Error,
+    events::{EventDetails, Events, StaticEvent},
+    Config,
+};
+use futures::{Stream, StreamExt};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A subscription to the events of each new block, as handed back by
+/// [`crate::events::EventsClient::subscribe_finalized()`] and
+/// [`crate::events::EventsClient::subscribe_best()`].
+///
+/// This hands back the [`Events`] of each block in turn. Use [`EventSubscription::filter()`]
+/// or [`EventSubscription::filter_pallet()`] to instead be handed back only the events of
+/// interest, across all of the blocks.
+pub struct EventSubscription<T: Config> {
+    inner: StreamOfResults<Events<T>>,
+}
+
+impl<T: Config> EventSubscription<T> {
+    /// Create a new [`EventSubscription`] which fetches the events of each block handed
+    /// back by the given block subscription.
+    pub fn new<C>(blocks: StreamOfResults<Block<T, C>>) -> Self
+    where
+        C: OnlineClientT<T>,
+    {
+        let events = blocks.then(|block| async move { block?.events().await });
+        EventSubscription {
+            inner: StreamOf::new(Box::pin(events)),
+        }
+    }
+
+    /// Returns the events of the next block. This is just a wrapper around
+    /// [`StreamExt::next()`] so that you can avoid the extra import.
+    pub async fn next(&mut self) -> Option<Result<Events<T>, Error>> {
+        self.inner.next().await
+    }
+
+    /// Hand back each event which decodes to the provided `Ev` type, across all of the
+    /// blocks, in the order that they were emitted. Events which aren't of the given type
+    /// are skipped over without being decoded into anything.
+    pub fn filter<Ev: StaticEvent + Send + 'static>(self) -> StreamOfResults<Ev> {
+        let events = self.inner.flat_map(|events| {
+            let events: Vec<Result<Ev, Error>> = match events {
+                Ok(events) => events.find::<Ev>().map(|ev| Ok(ev?)).collect(),
+                Err(e) => vec![Err(e)],
+            };
+            futures::stream::iter(events)
+        });
+        StreamOf::new(Box::pin(events))
+    }
+
+    /// Hand back each event emitted by the pallet with the given name, across all of the
+    /// blocks, in the order that they were emitted. The fields of the returned events are
+    /// not decoded into anything until asked for.
+    pub fn filter_pallet(self, pallet_name: impl Into<String>) -> StreamOfResults<EventDetails<T>> {
+        let pallet_name = pallet_name.into();
+        let events = self.inner.flat_map(move |events| {
+            let events: Vec<Result<EventDetails<T>, Error>> = match events {
+                Ok(events) => events.find_pallet(&pallet_name).map(|ev| Ok(ev?)).collect(),
+                Err(e) => vec![Err(e)],
+            };
+            futures::stream::iter(events)
+        });
+        StreamOf::new(Box::pin(events))
+    }
+}
+
+impl<T: Config> Stream for EventSubscription<T> {
+    type Item = Result<Events<T>, Error>;
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+}
+
+impl<T: Config> std::fmt::Debug for EventSubscription<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventSubscription").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::backend::{
+        legacy::rpc_methods::Bytes,
+        rpc::RawRpcSubscription,
+        test::{mock_online_client, rpc_client::MockRpcBuilder},
+    };
+    use crate::config::substrate::{BlakeTwo256, SubstrateHeader};
+    use crate::config::Header;
+    use crate::events::Phase;
+    use crate::utils::{AccountId32, H256};
+    use crate::Metadata;
+    use codec::{Compact, Decode, Encode};
+    use serde_json::value::RawValue;
+    use std::collections::HashMap;
+
+    #[derive(Debug, PartialEq, scale_decode::DecodeAsType)]
+    struct NewAccount {
+        account: AccountId32,
+    }
+
+    impl StaticEvent for NewAccount {
+        const PALLET: &'static str = "System";
+        const EVENT: &'static str = "NewAccount";
+    }
+
+    // Build the events of a block, given the pallet name, event name and encoded fields
+    // of each event.
+    fn events_bytes(events: &[(&str, &str, Vec<u8>)]) -> Vec<u8> {
+        let metadata_bytes = include_bytes!("../../../artifacts/polkadot_metadata_small.scale");
+        let metadata = Metadata::decode(&mut &metadata_bytes[..]).unwrap();
+
+        let mut bytes = Compact(events.len() as u32).encode();
+        for (pallet_name, event_name, fields) in events {
+            let pallet = metadata.pallet_by_name(pallet_name).unwrap();
+            let variant = pallet.event_variant_by_name(event_name).unwrap();
+            bytes.extend(Phase::ApplyExtrinsic(0).encode());
+            bytes.extend([pallet.index(), variant.index]);
+            bytes.extend(fields);
+            bytes.extend(Compact(0u32).encode());
+        }
+        bytes
+    }
+
+    #[tokio::test]
+    async fn filter_events_across_blocks() {
+        let header = |number: u32| SubstrateHeader::<u32, BlakeTwo256> {
+            parent_hash: H256::zero(),
+            number,
+            state_root: H256::zero(),
+            extrinsics_root: H256::zero(),
+            digest: Default::default(),
+        };
+        let account = |n: u8| AccountId32([n; 32]);
+        let transfer = (account(1), account(2), 100u128).encode();
+
+        // The events of blocks #1 and #2, by block hash:
+        let events = HashMap::from([
+            (
+                header(1).hash(),
+                events_bytes(&[
+                    ("System", "NewAccount", account(1).encode()),
+                    ("Balances", "Transfer", transfer.clone()),
+                ]),
+            ),
+            (
+                header(2).hash(),
+                events_bytes(&[
+                    ("Balances", "Transfer", transfer),
+                    ("System", "NewAccount", account(2).encode()),
+                    ("System", "KilledAccount", account(1).encode()),
+                ]),
+            ),
+        ]);
+        let rpc_client = MockRpcBuilder::default()
+            .add_subscription("chain_subscribeNewHeads", move |_, _, _| {
+                let headers = [header(1), header(2)].map(|h| {
+                    Ok(RawValue::from_string(serde_json::to_string(&h).unwrap()).unwrap())
+                });
+                Box::pin(async move {
+                    Ok(RawRpcSubscription {
+                        stream: futures::stream::iter(headers).boxed(),
+                        id: Some("ID".to_string()),
+                    })
+                })
+            })
+            .add_method("state_getStorage", move |_, _, params| {
+                let events = events.clone();
+                Box::pin(async move {
+                    let params = params.map(|p| p.get().to_string());
+                    let rpc_params = jsonrpsee::types::Params::new(params.as_deref());
+                    let mut rpc_params = rpc_params.sequence();
+                    let _key: Bytes = rpc_params.next().unwrap();
+                    let at: H256 = rpc_params.next().unwrap();
+                    let value = Bytes(events[&at].clone());
+                    Ok(RawValue::from_string(serde_json::to_string(&value).unwrap()).unwrap())
+                })
+            })
+            .build();
+        let client = mock_online_client(rpc_client);
+
+        let new_accounts: Vec<AccountId32> = client
+            .events()
+            .subscribe_best()
+            .await
+            .unwrap()
+            .filter::<NewAccount>()
+            .map(|ev| ev.unwrap().account)
+            .collect()
+            .await;
+        assert_eq!(new_accounts, vec![account(1), account(2)]);
+
+        let system_events: Vec<String> = client
+            .events()
+            .subscribe_best()
+            .await
+            .unwrap()
+            .filter_pallet("System")
+            .map(|ev| ev.unwrap().variant_name().to_owned())
+            .collect()
+            .await;
+        assert_eq!(system_events, ["NewAccount", "NewAccount", "KilledAccount"]);
+    }
+}
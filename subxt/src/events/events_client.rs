@@ -3,7 +3,13 @@
 // see LICENSE for license details.
 
 use crate::backend::{Backend, BackendExt, BlockRef};
-use crate::{client::OnlineClientT, error::Error, events::Events, Config};
+use crate::{
+    blocks::BlocksClient,
+    client::OnlineClientT,
+    error::Error,
+    events::{EventSubscription, Events},
+    Config,
+};
 use derive_where::derive_where;
 use polkadot_sdk::sp_crypto_hashing;
 use std::future::Future;
@@ -49,6 +55,26 @@ where
         self.at_or_latest(None)
     }
 
+    /// Subscribe to the events of each finalized block.
+    pub fn subscribe_finalized(
+        &self,
+    ) -> impl Future<Output = Result<EventSubscription<T>, Error>> + Send + 'static {
+        let blocks = BlocksClient::new(self.client.clone()).subscribe_finalized();
+        async move { Ok(EventSubscription::new(blocks.await?)) }
+    }
+
+    /// Subscribe to the events of each new block imported by the node onto the current
+    /// best fork.
+    ///
+    /// **Note:** You probably want to use [`Self::subscribe_finalized()`] most of
+    /// the time.
+    pub fn subscribe_best(
+        &self,
+    ) -> impl Future<Output = Result<EventSubscription<T>, Error>> + Send + 'static {
+        let blocks = BlocksClient::new(self.client.clone()).subscribe_best();
+        async move { Ok(EventSubscription::new(blocks.await?)) }
+    }
+
     /// Obtain events at some block hash.
     fn at_or_latest(
         &self,
@@ -90,3 +116,25 @@ pub(crate) async fn get_event_bytes<T: Config>(
         .await?
         .unwrap_or_default())
 }
+
+#[cfg(test)]
+mod test {
+    use crate::backend::test::{mock_online_client, rpc_client::MockRpcBuilder};
+    use primitive_types::H256;
+    use serde_json::value::RawValue;
+
+    #[tokio::test]
+    async fn missing_events_storage_means_no_events() {
+        // Very old blocks may have nothing stored at System.Events:
+        let rpc_client = MockRpcBuilder::default()
+            .add_method("state_getStorage", |_, _, _| {
+                Box::pin(async move { Ok(RawValue::from_string("null".to_string()).unwrap()) })
+            })
+            .build();
+        let client = mock_online_client(rpc_client);
+
+        let events = client.events().at(H256::zero()).await.unwrap();
+        assert!(events.is_empty());
+        assert_eq!(events.iter().count(), 0);
+    }
+}
@@ -9,9 +9,11 @@ use crate::client::OnlineClientT;
 use crate::Error;
 use subxt_core::{Config, Metadata};
 
+mod event_subscription;
 mod events_client;
+pub use event_subscription::EventSubscription;
 pub use events_client::EventsClient;
-pub use subxt_core::events::{EventDetails, Events, Phase, StaticEvent};
+pub use subxt_core::events::{EventDetails, Events, GroupedEvents, Phase, RawEvent, StaticEvent};
 
 /// Creates a new [`Events`] instance by fetching the corresponding bytes at `block_hash` from the client.
 pub async fn new_events_from_client<T, C>(
@@ -26,3 +28,92 @@ where
     let event_bytes = events_client::get_event_bytes(client.backend(), block_hash).await?;
     Ok(Events::<T>::decode_from(event_bytes, metadata))
 }
+
+/// Decode the events of many blocks in parallel on the blocking thread pool, handing back
+/// the events of each block in the same order that the blocks were given. At most
+/// `max_in_flight` blocks are decoded (and held in memory) at once, and an error decoding
+/// the events of one block is handed back in its place without affecting the others.
+///
+/// The given stream should hand back the hash of each block alongside the raw bytes of its
+/// events, as fetched from the `System.Events` storage entry, and `metadata` should be valid
+/// for all of the blocks.
+#[cfg(all(feature = "runtime", not(target_family = "wasm")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "runtime")))]
+pub fn decode_in_parallel<T, S>(
+    event_bytes: S,
+    metadata: Metadata,
+    max_in_flight: usize,
+) -> impl futures::Stream<Item = Result<(T::Hash, Vec<EventDetails<T>>), Error>> + Send
+where
+    T: Config,
+    S: futures::Stream<Item = (T::Hash, Vec<u8>)> + Send,
+{
+    use futures::StreamExt;
+
+    event_bytes
+        .map(move |(block_hash, event_bytes)| {
+            let metadata = metadata.clone();
+            async move {
+                let events = tokio::task::spawn_blocking(move || {
+                    Events::<T>::decode_from(event_bytes, metadata)
+                        .iter()
+                        .collect::<Vec<_>>()
+                })
+                .await
+                .map_err(|e| {
+                    Error::Other(format!(
+                        "Decoding the events of block {block_hash:?} did not complete: {e}"
+                    ))
+                })?;
+                let events = events.into_iter().collect::<Result<Vec<_>, _>>()?;
+                Ok((block_hash, events))
+            }
+        })
+        .buffered(max_in_flight.max(1))
+}
+
+#[cfg(all(test, feature = "runtime"))]
+mod test {
+    use super::*;
+    use crate::PolkadotConfig;
+    use codec::{Compact, Decode, Encode};
+    use futures::StreamExt;
+    use primitive_types::H256;
+
+    #[tokio::test]
+    async fn decode_in_parallel_preserves_block_order() {
+        let metadata_bytes = include_bytes!("../../../artifacts/polkadot_metadata_small.scale");
+        let metadata = Metadata::decode(&mut &metadata_bytes[..]).unwrap();
+
+        let no_events = Compact(0u32).encode();
+        // One event in the ApplyExtrinsic(0) phase, from a pallet which doesn't exist:
+        let mut bad_events = Compact(1u32).encode();
+        bad_events.extend(Phase::ApplyExtrinsic(0).encode());
+        bad_events.extend([255, 0]);
+
+        let blocks = (0..6u64).map(|n| {
+            let bytes = if n == 3 {
+                bad_events.clone()
+            } else {
+                no_events.clone()
+            };
+            (H256::from_low_u64_le(n), bytes)
+        });
+
+        let results: Vec<_> =
+            decode_in_parallel::<PolkadotConfig, _>(futures::stream::iter(blocks), metadata, 2)
+                .collect()
+                .await;
+
+        assert_eq!(results.len(), 6);
+        for (n, result) in results.into_iter().enumerate() {
+            if n == 3 {
+                assert!(result.is_err());
+            } else {
+                let (hash, events) = result.unwrap();
+                assert_eq!(hash, H256::from_low_u64_le(n as u64));
+                assert!(events.is_empty());
+            }
+        }
+    }
+}
@@ -57,14 +57,14 @@ pub mod utils;
 /// This module provides a [`Config`] type, which is used to define various
 /// types that are important in order to speak to a particular chain.
 /// [`SubstrateConfig`] provides a default set of these types suitable for the
-/// default Substrate node implementation, and [`PolkadotConfig`] for a
-/// Polkadot node.
+/// default Substrate node implementation, and [`PolkadotConfig`] and
+/// [`KusamaConfig`] for Polkadot and Kusama nodes.
 pub mod config {
     pub use subxt_core::config::{
-        polkadot, signed_extensions, substrate, BlockHash, Config, DefaultExtrinsicParams,
+        kusama, polkadot, signed_extensions, substrate, BlockHash, Config, DefaultExtrinsicParams,
         DefaultExtrinsicParamsBuilder, ExtrinsicParams, ExtrinsicParamsEncoder, Hasher, Header,
-        PolkadotConfig, PolkadotExtrinsicParams, RefineParams, RefineParamsData, SignedExtension,
-        SubstrateConfig, SubstrateExtrinsicParams,
+        KusamaConfig, KusamaExtrinsicParams, PolkadotConfig, PolkadotExtrinsicParams, RefineParams,
+        RefineParamsData, SignedExtension, SubstrateConfig, SubstrateExtrinsicParams,
     };
     pub use subxt_core::error::ExtrinsicParamsError;
 }
@@ -96,7 +96,7 @@ cfg_unstable_light_client! {
 // but leave most types behind their respective modules.
 pub use crate::{
     client::{OfflineClient, OnlineClient},
-    config::{Config, PolkadotConfig, SubstrateConfig},
+    config::{Config, KusamaConfig, PolkadotConfig, SubstrateConfig},
     error::Error,
     metadata::Metadata,
 };
@@ -5,6 +5,7 @@
 use crate::{
     backend::{BackendExt, BlockRef},
     client::OnlineClientT,
+    dynamic::DecodedValue,
     error::{Error, MetadataError, StorageAddressError},
     metadata::DecodeWithMetadata,
     Config,
@@ -63,6 +64,52 @@ where
         }
     }
 
+    /// Fetch the raw encoded value at the key given, and decode it into a [`DecodedValue`]
+    /// using the type of the named storage entry in the metadata. This is useful when the
+    /// key has already been hashed, for instance when it was obtained via
+    /// [`Self::fetch_raw_keys()`], and so cannot be turned into a storage address.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use subxt::{ PolkadotConfig, OnlineClient };
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let api = OnlineClient::<PolkadotConfig>::new().await.unwrap();
+    /// let storage = api.storage().at_latest().await.unwrap();
+    ///
+    /// // The root bytes of the "System.Account" map, to find the keys underneath:
+    /// let root = subxt::storage::dynamic("System", "Account", ()).to_root_bytes();
+    /// let mut keys = storage.fetch_raw_keys(root).await.unwrap();
+    ///
+    /// let key = keys.next().await.unwrap().unwrap();
+    /// let value = storage
+    ///     .fetch_raw_dynamic("System", "Account", key)
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// println!("Value: {:?}", value);
+    /// # }
+    /// ```
+    pub fn fetch_raw_dynamic(
+        &self,
+        pallet_name: impl Into<String>,
+        entry_name: impl Into<String>,
+        key: impl Into<Vec<u8>>,
+    ) -> impl Future<Output = Result<Option<DecodedValue>, Error>> + 'static {
+        let address = subxt_core::storage::address::dynamic(pallet_name, entry_name, ());
+        let metadata = self.client.metadata();
+        let fetch = self.fetch_raw(key);
+        async move {
+            let Some(data) = fetch.await? else {
+                return Ok(None);
+            };
+            let val = subxt_core::storage::decode_value(&mut &*data, &address, &metadata)?;
+            Ok(Some(val.to_value()?))
+        }
+    }
+
     /// Stream all of the raw keys underneath the key given
     pub fn fetch_raw_keys(
         &self,
@@ -322,3 +369,45 @@ pub struct StorageKeyValuePair<T: Address> {
     /// The value of the storage entry.
     pub value: T::Target,
 }
+
+#[cfg(test)]
+mod test {
+    use crate::backend::legacy::rpc_methods::Bytes;
+    use crate::backend::test::{mock_online_client, rpc_client::MockRpcBuilder};
+    use codec::Encode;
+    use primitive_types::H256;
+    use serde_json::value::RawValue;
+
+    #[tokio::test]
+    async fn fetch_raw_dynamic_decodes_using_entry_type() {
+        let rpc_client = MockRpcBuilder::default()
+            .add_method("state_getStorage", |_, _, _| {
+                Box::pin(async move {
+                    let value = serde_json::to_string(&Bytes(1234u32.encode())).unwrap();
+                    Ok(RawValue::from_string(value).unwrap())
+                })
+            })
+            .build();
+        let client = mock_online_client(rpc_client);
+
+        // System.Number is a u32:
+        let key = subxt_core::storage::address::dynamic("System", "Number", ()).to_root_bytes();
+        let storage = client.storage().at(H256::zero());
+
+        let value = storage
+            .fetch_raw_dynamic("System", "Number", key.clone())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(value.as_u128(), Some(1234));
+
+        let err = storage
+            .fetch_raw_dynamic("System", "NotAnEntry", key)
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("NotAnEntry"),
+            "unexpected error: {err}"
+        );
+    }
+}
@@ -0,0 +1,28 @@
+// Copyright 2019-2024 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use crate::{config::Config, error::Error};
+use std::future::Future;
+
+/// Like [`crate::tx::Signer`], but for signers which cannot sign a payload synchronously.
+/// This is useful when keys are held by some remote signing service or hardware device,
+/// which needs a round trip (and may refuse) in order to hand back a signature.
+///
+/// An [`AsyncSigner`] is used via [`crate::tx::PartialExtrinsic::sign_async()`], and via
+/// the `*_async` methods on [`crate::tx::TxClient`].
+pub trait AsyncSigner<T: Config> {
+    /// Return the "from" account ID.
+    fn account_id(&self) -> T::AccountId;
+
+    /// Return the "from" address.
+    fn address(&self) -> T::Address;
+
+    /// Takes a signer payload for an extrinsic, and returns a signature based on it, or an
+    /// error if a signature could not be obtained. The returned future is `Send`, so that
+    /// the futures which sign and submit extrinsics can be spawned onto other threads.
+    fn sign(
+        &self,
+        signer_payload: &[u8],
+    ) -> impl Future<Output = Result<T::Signature, Error>> + Send;
+}
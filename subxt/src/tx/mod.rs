@@ -11,6 +11,8 @@
 
 use crate::macros::cfg_substrate_compat;
 
+mod async_signer;
+mod nonce_manager;
 mod tx_client;
 mod tx_progress;
 
@@ -20,6 +22,8 @@ cfg_substrate_compat! {
     pub use subxt_core::tx::signer::PairSigner;
 }
 
+pub use async_signer::AsyncSigner;
+pub use nonce_manager::NonceManager;
 pub use subxt_core::tx::payload::{dynamic, DefaultPayload, DynamicPayload, Payload};
 pub use subxt_core::tx::signer::{self, Signer};
 pub use tx_client::{
@@ -0,0 +1,165 @@
+// Copyright 2019-2024 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use crate::{
+    backend::legacy::LegacyRpcMethods,
+    client::OnlineClientT,
+    config::Config,
+    error::{Error, RpcError},
+};
+use derive_where::derive_where;
+use futures::{future::BoxFuture, lock::Mutex as AsyncMutex, FutureExt};
+use serde::Serialize;
+use std::sync::Arc;
+
+/// Fetches the next nonce for an account from the node.
+type FetchNonce = Arc<dyn Fn() -> BoxFuture<'static, Result<u64, Error>> + Send + Sync>;
+
+/// Hands out sequential nonces for a single account, so that many extrinsics from that
+/// account can be in flight at once without asking the node for a nonce each time.
+///
+/// The account nonce is fetched from the node the first time that a nonce is asked for,
+/// and is then incremented locally for each nonce handed out. If an extrinsic is dropped
+/// or deemed invalid, call [`NonceManager::reset()`] so that the next nonce is fetched
+/// from the node again.
+///
+/// A [`NonceManager`] created via [`NonceManager::from_legacy_rpc()`] fetches the nonce
+/// with `system_accountNextIndex`, which takes the extrinsics in the node's transaction
+/// pool into account. One created via [`NonceManager::new()`] fetches the nonce at the
+/// current best block, and so does not know about extrinsics which are still in the pool;
+/// resetting it while other extrinsics are in flight may hand out nonces that they use.
+///
+/// Cloning a [`NonceManager`] hands back another handle to the same nonce. It does not
+/// limit how many extrinsics are in flight at once; that is left to the caller (for
+/// instance via [`futures::StreamExt::buffer_unordered`]). The nonces handed out can be
+/// used via [`crate::config::DefaultExtrinsicParamsBuilder::nonce()`].
+#[derive_where(Clone; T::AccountId)]
+pub struct NonceManager<T: Config> {
+    account_id: T::AccountId,
+    fetch_nonce: FetchNonce,
+    next_nonce: Arc<AsyncMutex<Option<u64>>>,
+}
+
+impl<T: Config> NonceManager<T>
+where
+    T::AccountId: Send + Sync,
+{
+    /// Create a new [`NonceManager`] for the given account, which fetches the account
+    /// nonce at the current best block.
+    pub fn new<C: OnlineClientT<T>>(client: C, account_id: T::AccountId) -> Self {
+        let fetch_account_id = account_id.clone();
+        let fetch_nonce: FetchNonce = Arc::new(move || {
+            let client = client.clone();
+            let account_id = fetch_account_id.clone();
+            async move {
+                let mut best_blocks = client.backend().stream_best_block_headers().await?;
+                let (_, block_ref) = best_blocks
+                    .next()
+                    .await
+                    .ok_or(Error::Rpc(RpcError::SubscriptionDropped))??;
+                crate::blocks::get_account_nonce(&client, &account_id, block_ref.hash()).await
+            }
+            .boxed()
+        });
+        Self::with_fetch_nonce(account_id, fetch_nonce)
+    }
+
+    /// Create a new [`NonceManager`] for the given account, which fetches the account
+    /// nonce via [`LegacyRpcMethods::system_account_next_index()`], and so takes into
+    /// account any extrinsics from the account which are in the transaction pool.
+    pub fn from_legacy_rpc(rpc: LegacyRpcMethods<T>, account_id: T::AccountId) -> Self
+    where
+        T::AccountId: Serialize,
+    {
+        let fetch_account_id = account_id.clone();
+        let fetch_nonce: FetchNonce = Arc::new(move || {
+            let rpc = rpc.clone();
+            let account_id = fetch_account_id.clone();
+            async move { rpc.system_account_next_index(&account_id).await }.boxed()
+        });
+        Self::with_fetch_nonce(account_id, fetch_nonce)
+    }
+
+    fn with_fetch_nonce(account_id: T::AccountId, fetch_nonce: FetchNonce) -> Self {
+        NonceManager {
+            account_id,
+            fetch_nonce,
+            next_nonce: Arc::new(AsyncMutex::new(None)),
+        }
+    }
+}
+
+impl<T: Config> NonceManager<T> {
+    /// The account that nonces are handed out for.
+    pub fn account_id(&self) -> &T::AccountId {
+        &self.account_id
+    }
+
+    /// Hand out the next nonce for the account, fetching the account nonce from the node
+    /// if this is the first nonce asked for since creation or the last reset.
+    pub async fn next_nonce(&self) -> Result<u64, Error> {
+        // Hold the lock while fetching, so that concurrent callers wait for the fetched
+        // nonce rather than each fetching (and handing out) the same one.
+        let mut next_nonce = self.next_nonce.lock().await;
+        let nonce = match *next_nonce {
+            Some(nonce) => nonce,
+            None => (self.fetch_nonce)().await?,
+        };
+        *next_nonce = Some(nonce + 1);
+        Ok(nonce)
+    }
+
+    /// Forget the locally tracked nonce, so that the next nonce handed out is fetched from
+    /// the node again. Call this when an extrinsic is dropped or deemed invalid.
+    pub async fn reset(&self) {
+        *self.next_nonce.lock().await = None;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::backend::test::rpc_client::MockRpcBuilder;
+    use crate::utils::AccountId32;
+    use crate::PolkadotConfig;
+    use serde_json::value::RawValue;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    #[tokio::test]
+    async fn hands_out_sequential_nonces_and_refetches_after_reset() {
+        // Each fetch hands back a nonce 10 higher than the last, starting at 10.
+        let fetches = Arc::new(AtomicU64::new(0));
+        let fetches2 = fetches.clone();
+        let rpc_client = MockRpcBuilder::default()
+            .add_method("system_accountNextIndex", move |_, _, _| {
+                let fetches = fetches2.clone();
+                Box::pin(async move {
+                    // Give other callers a chance to ask for a nonce mid fetch.
+                    tokio::task::yield_now().await;
+                    let n = fetches.fetch_add(1, Ordering::SeqCst) + 1;
+                    Ok(RawValue::from_string((n * 10).to_string()).unwrap())
+                })
+            })
+            .build();
+        let rpc = LegacyRpcMethods::<PolkadotConfig>::new(rpc_client.into());
+        let manager = NonceManager::from_legacy_rpc(rpc, AccountId32([1; 32]));
+
+        // Concurrent callers share a single fetch and each get their own nonce:
+        let mut nonces: Vec<u64> = futures::future::try_join_all((0..5).map(|_| {
+            let manager = manager.clone();
+            async move { manager.next_nonce().await }
+        }))
+        .await
+        .unwrap();
+        nonces.sort();
+        assert_eq!(nonces, vec![10, 11, 12, 13, 14]);
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+        // Resetting fetches the nonce from the node again:
+        manager.reset().await;
+        assert_eq!(manager.next_nonce().await.unwrap(), 20);
+        assert_eq!(manager.next_nonce().await.unwrap(), 21);
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+    }
+}
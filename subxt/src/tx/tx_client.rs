@@ -7,7 +7,8 @@ use crate::{
     client::{OfflineClientT, OnlineClientT},
     config::{Config, ExtrinsicParams, Header, RefineParams, RefineParamsData},
     error::{BlockError, Error},
-    tx::{Payload, Signer as SignerT, TxProgress},
+    events::StaticEvent,
+    tx::{AsyncSigner, NonceManager, Payload, Signer as SignerT, TxProgress},
     utils::PhantomDataSendSync,
 };
 use codec::{Compact, Decode, Encode};
@@ -141,6 +142,16 @@ where
         crate::blocks::get_account_nonce(&self.client, account_id, block_ref.hash()).await
     }
 
+    /// Create a [`NonceManager`] which hands out sequential nonces for the given account,
+    /// fetching the account nonce at the current best block. See
+    /// [`NonceManager::from_legacy_rpc()`] for one which knows about the transaction pool.
+    pub fn nonce_manager(&self, account_id: T::AccountId) -> NonceManager<T>
+    where
+        T::AccountId: Send + Sync,
+    {
+        NonceManager::new(self.client.clone(), account_id)
+    }
+
     /// Creates a partial signed extrinsic, without submitting it.
     pub async fn create_partial_signed<Call>(
         &self,
@@ -221,6 +232,28 @@ where
             .await
     }
 
+    /// Creates and signs an extrinsic, submits it to the chain and waits for it to be
+    /// finalized, returning the first event of type `Ev` that it emitted.
+    ///
+    /// See [`TxProgress::wait_for_finalized_event()`] for the errors returned if the
+    /// extrinsic fails or does not emit such an event.
+    pub async fn sign_and_submit_then_watch_for<Ev, Call, Signer>(
+        &self,
+        call: &Call,
+        signer: &Signer,
+        params: <T::ExtrinsicParams as ExtrinsicParams<T>>::Params,
+    ) -> Result<Ev, Error>
+    where
+        Ev: StaticEvent,
+        Call: Payload,
+        Signer: SignerT<T>,
+    {
+        self.sign_and_submit_then_watch(call, signer, params)
+            .await?
+            .wait_for_finalized_event()
+            .await
+    }
+
     /// Creates and signs an extrinsic and submits to the chain for block inclusion. Passes
     /// default parameters to construct the "signed extra" and "additional" payloads needed
     /// by the extrinsic.
@@ -267,6 +300,72 @@ where
             .submit()
             .await
     }
+
+    /// Creates a signed extrinsic, without submitting it. This is like
+    /// [`TxClient::create_signed()`], but accepts an [`AsyncSigner`], which may need to wait
+    /// for (or fail to obtain) a signature from elsewhere.
+    pub async fn create_signed_async<Call, Signer>(
+        &self,
+        call: &Call,
+        signer: &Signer,
+        params: <T::ExtrinsicParams as ExtrinsicParams<T>>::Params,
+    ) -> Result<SubmittableExtrinsic<T, C>, Error>
+    where
+        Call: Payload,
+        Signer: AsyncSigner<T>,
+    {
+        self.validate(call)?;
+        let partial_signed = self
+            .create_partial_signed(call, &signer.account_id(), params)
+            .await?;
+
+        // This doesn't use `sign_async`, which would hold a reference to the partial
+        // extrinsic (which is not `Sync`) across the await, and so stop this future
+        // from being `Send`.
+        let signature = signer.sign(&partial_signed.signer_payload()).await?;
+        Ok(partial_signed.sign_with_address_and_signature(&signer.address(), &signature))
+    }
+
+    /// Creates and signs an extrinsic and submits it to the chain. This is like
+    /// [`TxClient::sign_and_submit_then_watch()`], but accepts an [`AsyncSigner`].
+    ///
+    /// Returns a [`TxProgress`], which can be used to track the status of the transaction
+    /// and obtain details about it, once it has made it into a block.
+    pub async fn sign_and_submit_then_watch_async<Call, Signer>(
+        &self,
+        call: &Call,
+        signer: &Signer,
+        params: <T::ExtrinsicParams as ExtrinsicParams<T>>::Params,
+    ) -> Result<TxProgress<T, C>, Error>
+    where
+        Call: Payload,
+        Signer: AsyncSigner<T>,
+    {
+        self.create_signed_async(call, signer, params)
+            .await?
+            .submit_and_watch()
+            .await
+    }
+
+    /// Creates and signs an extrinsic and submits to the chain for block inclusion. This is
+    /// like [`TxClient::sign_and_submit()`], but accepts an [`AsyncSigner`].
+    ///
+    /// Returns `Ok` with the extrinsic hash if it is valid extrinsic.
+    pub async fn sign_and_submit_async<Call, Signer>(
+        &self,
+        call: &Call,
+        signer: &Signer,
+        params: <T::ExtrinsicParams as ExtrinsicParams<T>>::Params,
+    ) -> Result<T::Hash, Error>
+    where
+        Call: Payload,
+        Signer: AsyncSigner<T>,
+    {
+        self.create_signed_async(call, signer, params)
+            .await?
+            .submit()
+            .await
+    }
 }
 
 /// This payload contains the information needed to produce an extrinsic.
@@ -305,6 +404,20 @@ where
         }
     }
 
+    /// Convert this [`PartialExtrinsic`] into a [`SubmittableExtrinsic`], ready to submit.
+    /// This is like [`PartialExtrinsic::sign()`], but accepts an [`AsyncSigner`], which may
+    /// need to wait for (or fail to obtain) a signature from elsewhere.
+    pub async fn sign_async<Signer>(
+        &self,
+        signer: &Signer,
+    ) -> Result<SubmittableExtrinsic<T, C>, Error>
+    where
+        Signer: AsyncSigner<T>,
+    {
+        let signature = signer.sign(&self.signer_payload()).await?;
+        Ok(self.sign_with_address_and_signature(&signer.address(), &signature))
+    }
+
     /// Convert this [`PartialExtrinsic`] into a [`SubmittableExtrinsic`], ready to submit.
     /// An address, and something representing a signature that can be SCALE encoded, are both
     /// needed in order to construct it. If you have a `Signer` to hand, you can use
@@ -625,6 +738,107 @@ pub enum TransactionInvalid {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::config::{polkadot::PolkadotExtrinsicParamsBuilder, PolkadotConfig};
+    use crate::utils::{AccountId32, MultiAddress, MultiSignature, H256};
+    use crate::{client::RuntimeVersion, Metadata, OfflineClient};
+
+    // Pretend to sign things remotely, for instance on some hardware wallet.
+    struct RemoteSigner {
+        refuse: bool,
+    }
+
+    impl AsyncSigner<PolkadotConfig> for RemoteSigner {
+        fn account_id(&self) -> AccountId32 {
+            AccountId32([1; 32])
+        }
+
+        fn address(&self) -> MultiAddress<AccountId32, ()> {
+            MultiAddress::Id(AccountId32([1; 32]))
+        }
+
+        async fn sign(&self, _signer_payload: &[u8]) -> Result<MultiSignature, Error> {
+            tokio::task::yield_now().await;
+            if self.refuse {
+                Err(Error::Other("The user refused to sign".into()))
+            } else {
+                Ok(MultiSignature::Sr25519([2; 64]))
+            }
+        }
+    }
+
+    // This only needs to compile: any signer can be used with any config, whatever its
+    // signature type is.
+    #[allow(dead_code)]
+    async fn sign_and_submit_accepts_any_signer<T, C, Call, S>(
+        client: &TxClient<T, C>,
+        call: &Call,
+        signer: &S,
+    ) where
+        T: Config,
+        C: OnlineClientT<T>,
+        Call: Payload,
+        S: SignerT<T>,
+        <T::ExtrinsicParams as ExtrinsicParams<T>>::Params: Default,
+    {
+        let _ = client.sign_and_submit_default(call, signer).await;
+    }
+
+    // This only needs to compile: submitting with any async signer can be spawned.
+    #[allow(dead_code)]
+    fn sign_and_submit_then_watch_async_is_send<C, Call, S>(
+        client: &TxClient<PolkadotConfig, C>,
+        call: &Call,
+        signer: &S,
+    ) where
+        C: OnlineClientT<PolkadotConfig>,
+        Call: Payload + Sync,
+        S: AsyncSigner<PolkadotConfig> + Sync,
+    {
+        fn assert_send<F: Send>(_: F) {}
+        assert_send(client.sign_and_submit_then_watch_async(call, signer, Default::default()));
+    }
+
+    #[tokio::test]
+    async fn partial_extrinsics_can_be_signed_asynchronously() {
+        let metadata_bytes = include_bytes!("../../../artifacts/polkadot_metadata_small.scale");
+        let metadata = Metadata::decode(&mut &metadata_bytes[..]).unwrap();
+        let runtime_version = RuntimeVersion {
+            spec_version: 0,
+            transaction_version: 0,
+        };
+        let client = OfflineClient::<PolkadotConfig>::new(H256::zero(), runtime_version, metadata);
+
+        let call = crate::dynamic::tx(
+            "System",
+            "remark",
+            vec![scale_value::Value::from_bytes([1, 2, 3])],
+        );
+        let params = PolkadotExtrinsicParamsBuilder::new().nonce(0).build();
+        let partial = client
+            .tx()
+            .create_partial_signed_offline(&call, params)
+            .unwrap();
+
+        let signed = partial
+            .sign_async(&RemoteSigner { refuse: false })
+            .await
+            .unwrap();
+        let expected = partial.sign_with_address_and_signature(
+            &MultiAddress::Id(AccountId32([1; 32])),
+            &MultiSignature::Sr25519([2; 64]),
+        );
+        assert_eq!(signed.encoded(), expected.encoded());
+
+        let err = partial
+            .sign_async(&RemoteSigner { refuse: true })
+            .await
+            .err()
+            .unwrap();
+        assert!(
+            err.to_string().contains("refused"),
+            "unexpected error: {err}"
+        );
+    }
 
     #[test]
     fn transaction_validity_decoding_empty_bytes() {
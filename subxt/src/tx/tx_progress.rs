@@ -10,7 +10,7 @@ use crate::{
     backend::{BlockRef, StreamOfResults, TransactionStatus as BackendTxStatus},
     client::OnlineClientT,
     error::{DispatchError, Error, RpcError, TransactionError},
-    events::EventsClient,
+    events::{EventsClient, StaticEvent},
     utils::strip_compact_prefix,
     Config,
 };
@@ -118,6 +118,27 @@ where
         let evs = self.wait_for_finalized().await?.wait_for_success().await?;
         Ok(evs)
     }
+
+    /// Wait for the transaction to be finalized and succeed, as per
+    /// [`TxProgress::wait_for_finalized_success()`], and then return the first event of
+    /// type `Ev` that it emitted. If the transaction failed, the [`DispatchError`] that it
+    /// failed with is returned, and if it succeeded without emitting such an event,
+    /// [`TransactionError::EventNotFound`] is returned.
+    ///
+    /// **Note:** consumes self. If you'd like to perform multiple actions as progress is made,
+    /// use [`TxProgress::next()`] instead.
+    pub async fn wait_for_finalized_event<Ev: StaticEvent>(self) -> Result<Ev, Error> {
+        self.wait_for_finalized_success()
+            .await?
+            .find_first::<Ev>()?
+            .ok_or_else(|| {
+                TransactionError::EventNotFound {
+                    pallet_name: Ev::PALLET.to_owned(),
+                    event_name: Ev::EVENT.to_owned(),
+                }
+                .into()
+            })
+    }
 }
 
 impl<T: Config, C: Clone> Stream for TxProgress<T, C> {
@@ -281,26 +302,7 @@ impl<T: Config, C: OnlineClientT<T>> TxInBlock<T, C> {
     /// **Note:** This has to download block details from the node and decode events
     /// from them.
     pub async fn fetch_events(&self) -> Result<crate::blocks::ExtrinsicEvents<T>, Error> {
-        let block_body = self
-            .client
-            .backend()
-            .block_body(self.block_ref.hash())
-            .await?
-            .ok_or(Error::Transaction(TransactionError::BlockNotFound))?;
-
-        let extrinsic_idx = block_body
-            .iter()
-            .position(|ext| {
-                use crate::config::Hasher;
-                let Ok((_, stripped)) = strip_compact_prefix(ext) else {
-                    return false;
-                };
-                let hash = T::Hasher::hash_of(&stripped);
-                hash == self.ext_hash
-            })
-            // If we successfully obtain the block hash we think contains our
-            // extrinsic, the extrinsic should be in there somewhere..
-            .ok_or(Error::Transaction(TransactionError::BlockNotFound))?;
+        let extrinsic_idx = self.extrinsic_index().await?;
 
         let events = EventsClient::new(self.client.clone())
             .at(self.block_ref.clone())
@@ -308,10 +310,47 @@ impl<T: Config, C: OnlineClientT<T>> TxInBlock<T, C> {
 
         Ok(crate::blocks::ExtrinsicEvents::new(
             self.ext_hash,
-            extrinsic_idx as u32,
+            extrinsic_idx,
             events,
         ))
     }
+
+    /// Fetch the index of this transaction within the block that it made it into. This is
+    /// the index that events emitted by the transaction are associated with, via
+    /// [`crate::events::Phase::ApplyExtrinsic`].
+    ///
+    /// **Note:** If the same extrinsic bytes appear more than once in the block (which a
+    /// node should never allow), the index of the first match is returned.
+    ///
+    /// **Note:** This has to download the block body from the node.
+    pub async fn extrinsic_index(&self) -> Result<u32, Error> {
+        let block_body = self
+            .client
+            .backend()
+            .block_body(self.block_ref.hash())
+            .await?
+            .ok_or(Error::Transaction(TransactionError::BlockNotFound))?;
+
+        find_extrinsic_index::<T>(&block_body, self.ext_hash)
+            // If we successfully obtain the block hash we think contains our
+            // extrinsic, the extrinsic should be in there somewhere..
+            .ok_or(Error::Transaction(TransactionError::BlockNotFound))
+    }
+}
+
+/// Find the index of the first extrinsic in the given block body whose hash matches `ext_hash`.
+fn find_extrinsic_index<T: Config>(block_body: &[Vec<u8>], ext_hash: T::Hash) -> Option<u32> {
+    block_body
+        .iter()
+        .position(|ext| {
+            use crate::config::Hasher;
+            let Ok((_, stripped)) = strip_compact_prefix(ext) else {
+                return false;
+            };
+            let hash = T::Hasher::hash_of(&stripped);
+            hash == ext_hash
+        })
+        .map(|idx| idx as u32)
 }
 
 #[cfg(test)]
@@ -402,6 +441,35 @@ mod test {
         ));
     }
 
+    #[test]
+    fn find_extrinsic_index_returns_first_match() {
+        use super::find_extrinsic_index;
+        use crate::config::Hasher;
+        use codec::Encode;
+
+        // Block bodies contain the length-prefixed extrinsic bytes, and the
+        // extrinsic hash is the hash of those bytes as reported by the node.
+        let ext_a = vec![1u8, 2, 3].encode();
+        let ext_b = vec![4u8, 5, 6].encode();
+        let hash_of = |ext: &[u8]| <SubstrateConfig as Config>::Hasher::hash(ext);
+
+        let body = vec![ext_a.clone(), ext_b.clone(), ext_b.clone()];
+
+        assert_eq!(
+            find_extrinsic_index::<SubstrateConfig>(&body, hash_of(&ext_a)),
+            Some(0)
+        );
+        // Duplicates shouldn't exist, but if they do we return the first one.
+        assert_eq!(
+            find_extrinsic_index::<SubstrateConfig>(&body, hash_of(&ext_b)),
+            Some(1)
+        );
+        assert_eq!(
+            find_extrinsic_index::<SubstrateConfig>(&body, hash_of(&[9, 9])),
+            None
+        );
+    }
+
     fn mock_tx_progress(statuses: Vec<MockSubstrateTxStatus>) -> MockTxProgress {
         let sub = create_substrate_tx_status_subscription(statuses);
         TxProgress::new(sub, MockClient, Default::default())
@@ -9,9 +9,9 @@ use crate::{error::RpcError, Error};
 use url::Url;
 
 pub use subxt_core::utils::{
-    bits, strip_compact_prefix, to_hex, AccountId32, Encoded, Era, KeyedVec, MultiAddress,
-    MultiSignature, PhantomDataSendSync, Static, UncheckedExtrinsic, WrapperKeepOpaque, Yes, H160,
-    H256, H512,
+    bits, strip_compact_prefix, to_hex, AccountId32, DispatchClass, DispatchInfo, Encoded, Era,
+    KeyedVec, MultiAddress, MultiSignature, Pays, PhantomDataSendSync, Static, UncheckedExtrinsic,
+    Weight, WrapperKeepOpaque, Yes, H160, H256, H512, SUBSTRATE_SS58_PREFIX,
 };
 
 cfg_jsonrpsee! {
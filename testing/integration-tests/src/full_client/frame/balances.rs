@@ -8,7 +8,7 @@ use crate::{
 };
 use codec::Decode;
 use subxt::{
-    error::{DispatchError, Error, TokenError},
+    error::{DispatchError, Error, TokenError, TransactionError},
     utils::{AccountId32, MultiAddress},
 };
 use subxt_signer::sr25519::dev;
@@ -65,6 +65,11 @@ async fn tx_basic_transfer() -> Result<(), subxt::Error> {
         .find_first::<system::events::ExtrinsicSuccess>()
         .expect("Failed to decode ExtrinisicSuccess")
         .expect("Failed to find ExtrinisicSuccess");
+    let outcome = events
+        .outcome()?
+        .expect("Failed to find the extrinsic outcome");
+    assert!(outcome.result.is_ok());
+    assert_eq!(outcome.dispatch_info.pays_fee, subxt::utils::Pays::Yes);
 
     let expected_event = balances::events::Transfer {
         from: alice.public_key().to_account_id(),
@@ -420,6 +425,101 @@ async fn transfer_implicit_subscription() {
     );
 }
 
+#[subxt_test]
+async fn transfer_then_watch_for_event() {
+    let alice = dev::alice();
+    let bob: AccountId32 = dev::bob().public_key().into();
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let to_bob_tx = node_runtime::tx()
+        .balances()
+        .transfer_allow_death(bob.clone().into(), 10_000);
+
+    let event = api
+        .tx()
+        .sign_and_submit_then_watch_for::<balances::events::Transfer, _, _>(
+            &to_bob_tx,
+            &alice,
+            Default::default(),
+        )
+        .await
+        .expect("Can find balance transfer event");
+
+    assert_eq!(
+        event,
+        balances::events::Transfer {
+            from: alice.public_key().to_account_id(),
+            to: bob,
+            amount: 10_000
+        }
+    );
+}
+
+#[subxt_test]
+async fn transfer_over_free_balance_then_watch_for_event() {
+    let bob = dev::bob();
+    let charlie: AccountId32 = dev::charlie().public_key().into();
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    // Bob can pay the fees, but doesn't have this much to transfer:
+    let to_charlie_tx = node_runtime::tx()
+        .balances()
+        .transfer_allow_death(charlie.into(), u128::MAX / 2);
+
+    let res = api
+        .tx()
+        .sign_and_submit_then_watch_for::<balances::events::Transfer, _, _>(
+            &to_charlie_tx,
+            &bob,
+            Default::default(),
+        )
+        .await;
+
+    assert!(
+        matches!(
+            res,
+            Err(Error::Runtime(DispatchError::Token(
+                TokenError::FundsUnavailable
+            )))
+        ),
+        "Expected an insufficient balance, got {res:?}"
+    );
+}
+
+#[subxt_test]
+async fn transfer_then_watch_for_missing_event() {
+    let alice = dev::alice();
+    let bob: AccountId32 = dev::bob().public_key().into();
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let to_bob_tx = node_runtime::tx()
+        .balances()
+        .transfer_allow_death(bob.into(), 10_000);
+
+    // A transfer succeeds without reserving anything:
+    let res = api
+        .tx()
+        .sign_and_submit_then_watch_for::<balances::events::Reserved, _, _>(
+            &to_bob_tx,
+            &alice,
+            Default::default(),
+        )
+        .await;
+
+    let Err(Error::Transaction(TransactionError::EventNotFound {
+        pallet_name,
+        event_name,
+    })) = res
+    else {
+        panic!("Expected the event not to be found, got {res:?}");
+    };
+    assert_eq!(pallet_name, "Balances");
+    assert_eq!(event_name, "Reserved");
+}
+
 #[subxt_test]
 async fn constant_existential_deposit() {
     let ctx = test_context().await;